@@ -0,0 +1,201 @@
+//! The accepted answers for each day and part, stored as a small subset of TOML:
+//!
+//! ```toml
+//! [day-1]
+//! part-1 = "1292"
+//! part-2 = "1262"
+//! ```
+
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::fmt::Write;
+use std::fs::File;
+
+use crate::ParseError;
+
+/// The name of the answers file, kept next to the inputs.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String
+    },
+    Unknown
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    entries: BTreeMap<(u32, u32), String>
+}
+
+fn parse_value(line: &crate::Line, value: &str) -> Result<String, ParseError> {
+    let quoted = match value.strip_prefix('"') {
+        Some(quoted) => quoted,
+        None if !value.is_empty() && !value.contains(char::is_whitespace) => {
+            return Ok(value.to_owned());
+        }
+        None => return Err(line.error_at(value, "expected a string or a number"))
+    };
+    let mut answer = String::new();
+    let mut chars = quoted.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => {
+                let rest = quoted[i + 1..].trim_start();
+                if !rest.is_empty() && !rest.starts_with('#') {
+                    return Err(line.error_at(rest, "unexpected text after the answer"));
+                }
+                return Ok(answer);
+            }
+            '\\' => answer.push(match chars.next() {
+                Some((_, 'n')) => '\n',
+                Some((_, 't')) => '\t',
+                Some((_, 'r')) => '\r',
+                Some((_, '"')) => '"',
+                Some((_, '\\')) => '\\',
+                _ => return Err(line.error_at(&quoted[i..], "invalid escape sequence"))
+            }),
+            _ => answer.push(c)
+        }
+    }
+    Err(line.error_at_end("unterminated string"))
+}
+
+fn escape(answer: &str) -> String {
+    let mut escaped = String::new();
+    for c in answer.chars() {
+        match c {
+            '\n' => escaped += "\\n",
+            '\t' => escaped += "\\t",
+            '\r' => escaped += "\\r",
+            '"' => escaped += "\\\"",
+            '\\' => escaped += "\\\\",
+            _ => escaped.push(c)
+        }
+    }
+    escaped
+}
+
+impl Answers {
+    pub fn parse(input: impl BufRead) -> Result<Self, ParseError> {
+        let mut answers = Self::default();
+        let mut day = None;
+        for line in crate::lines(input) {
+            let line = line?;
+            let text = line.text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            if let Some(table) = text.strip_prefix('[') {
+                let table = table
+                    .strip_suffix(']')
+                    .ok_or_else(|| line.error_at_end("expected `]`"))?;
+                let n = line.strip_prefix(table, "day-")?;
+                day = Some(line.parse(n)?);
+                continue;
+            }
+            let day = day.ok_or_else(|| line.error_at(text, "expected a `[day-N]` table"))?;
+            let (key, value) = line.split_once(text, "=")?;
+            let part = line.strip_prefix(key.trim_end(), "part-")?;
+            let part = line.parse(part)?;
+            if !(1..=2).contains(&part) {
+                return Err(line.error_at(key, "expected `part-1` or `part-2`"));
+            }
+            answers.entries.insert((day, part), parse_value(&line, value.trim())?);
+        }
+        Ok(answers)
+    }
+
+    /// Loads the answers in `path`; a missing file has no answers.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match File::open(path) {
+            Ok(file) => Self::parse(BufReader::new(file))
+                .map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("could not open `{}`: {}", path.display(), e))
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        std::fs::write(path, self.to_toml())
+            .map_err(|e| format!("could not write `{}`: {}", path.display(), e))
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        let mut current_day = None;
+        for (&(day, part), answer) in &self.entries {
+            if current_day != Some(day) {
+                if current_day.is_some() {
+                    toml.push('\n');
+                }
+                writeln!(toml, "[day-{}]", day).unwrap();
+                current_day = Some(day);
+            }
+            writeln!(toml, "part-{} = \"{}\"", part, escape(answer)).unwrap();
+        }
+        toml
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.entries.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u32, part: u32, answer: &str) {
+        self.entries.insert((day, part), answer.trim_end().to_owned());
+    }
+
+    pub fn check(&self, day: u32, part: u32, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected.trim_end() == answer.trim_end() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_owned()
+            },
+            None => Verdict::Unknown
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "\
+# Accepted answers
+[day-1]
+part-1 = \"7\"
+part-2 = 5
+
+[day-13]
+part-2 = \"# #\\n###\\n\"  # glyphs
+";
+
+    #[test]
+    fn checks_answers() {
+        let answers = Answers::parse(ANSWERS.as_bytes()).unwrap();
+        assert_eq!(answers.check(1, 1, "7"), Verdict::Pass);
+        assert_eq!(answers.check(1, 2, "6"), Verdict::Fail { expected: "5".to_owned() });
+        assert_eq!(answers.check(2, 1, "150"), Verdict::Unknown);
+        assert_eq!(answers.check(13, 2, "# #\n###"), Verdict::Pass);
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let mut answers = Answers::default();
+        answers.insert(13, 2, "\"#\" \\\n");
+        answers.insert(1, 1, "7");
+        let toml = answers.to_toml();
+        assert_eq!(Answers::parse(toml.as_bytes()).unwrap(), answers);
+    }
+
+    #[test]
+    fn reports_error_positions() {
+        let error = Answers::parse("[day-1]\npart-3 = \"1\"\n".as_bytes()).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = Answers::parse("part-1 = \"1\"\n".as_bytes()).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+}
@@ -0,0 +1,215 @@
+//! Regression checks against the same store the runner uses: every
+//! `day-NN.txt` in an inputs directory, checked against the accepted answers
+//! in the [`ANSWERS_FILE`] next to them. A part with no recorded answer is not
+//! checked, but every input must have at least one, and a directory with no
+//! inputs is an error rather than nothing to check.
+//!
+//! [`main!`](crate::main) runs this over the inputs directory as an ignored
+//! test, so `cargo test -- --ignored` checks every saved input.
+
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::fmt::{self, Display};
+use std::fs::File;
+
+use crate::ParseError;
+use crate::answers::{Answers, Verdict, ANSWERS_FILE};
+use crate::input;
+use crate::runner::Day;
+
+#[derive(Debug)]
+pub enum FixtureError {
+    Io(PathBuf, std::io::Error),
+    Answers(String),
+    Parse(PathBuf, ParseError),
+    /// The directory has no input for any of the days.
+    NoInputs(PathBuf),
+    /// The answers file has nothing recorded for a day with an input.
+    NoAnswers(PathBuf, u32),
+    Mismatch {
+        fixture: PathBuf,
+        part: u32,
+        expected: String,
+        actual: String
+    }
+}
+
+impl Display for FixtureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+            Self::Answers(e) => write!(f, "{}", e),
+            Self::Parse(path, e) => write!(f, "{}: {}", path.display(), e),
+            Self::NoInputs(dir) => write!(f, "{}: no inputs to check", dir.display()),
+            Self::NoAnswers(path, day) => {
+                write!(f, "{}: no answers recorded for day {}", path.display(), day)
+            }
+            Self::Mismatch { fixture, part, expected, actual } => write!(
+                f,
+                "{} part {}: expected `{}`, got `{}`",
+                fixture.display(),
+                part,
+                expected,
+                actual
+            )
+        }
+    }
+}
+
+fn check_day(
+    day: &Day,
+    dir: &Path,
+    answers: &Answers,
+    errors: &mut Vec<FixtureError>
+) -> Option<usize> {
+    let input = input::day_path(dir, day.day);
+    let file = match File::open(&input) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(e) => {
+            errors.push(FixtureError::Io(input, e));
+            return Some(0);
+        }
+    };
+    if (1..=2).all(|part| answers.get(day.day, part).is_none()) {
+        errors.push(FixtureError::NoAnswers(dir.join(ANSWERS_FILE), day.day));
+        return Some(0);
+    }
+    let parsed = match (day.parse)(&mut BufReader::new(file)) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            errors.push(FixtureError::Parse(input, e));
+            return Some(0);
+        }
+    };
+    let mut checked = 0;
+    for part in 1..=2 {
+        if answers.get(day.day, part).is_none() {
+            continue;
+        }
+        let actual = parsed.part(part);
+        if let Verdict::Fail { expected } = answers.check(day.day, part, &actual) {
+            errors.push(FixtureError::Mismatch { fixture: input.clone(), part, expected, actual });
+        }
+        checked += 1;
+    }
+    Some(checked)
+}
+
+/// Checks the input in `dir` for each of `days` that has one, returning how
+/// many answers were checked.
+pub fn check(days: &[Day], dir: impl AsRef<Path>) -> Result<usize, Vec<FixtureError>> {
+    let dir = dir.as_ref();
+    let answers = Answers::load(&dir.join(ANSWERS_FILE))
+        .map_err(|e| vec![FixtureError::Answers(e)])?;
+    let mut errors = Vec::new();
+    let mut inputs = 0;
+    let mut checked = 0;
+    for day in days {
+        if let Some(n) = check_day(day, dir, &answers, &mut errors) {
+            inputs += 1;
+            checked += n;
+        }
+    }
+    if inputs == 0 {
+        errors.push(FixtureError::NoInputs(dir.to_owned()));
+    }
+    if errors.is_empty() {
+        Ok(checked)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Like [`check`], but panics listing every failed answer.
+pub fn assert_fixtures(days: &[Day], dir: impl AsRef<Path>) {
+    if let Err(errors) = check(days, dir) {
+        let errors = errors
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        panic!("{} fixture(s) failed:\n{}", errors.len(), errors.join("\n"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufRead;
+    use std::fs;
+    use crate::Solution;
+    use super::*;
+
+    struct Length;
+
+    impl Solution for Length {
+        type Input = String;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(mut input: impl BufRead) -> Result<Self::Input, ParseError> {
+            let mut text = String::new();
+            input
+                .read_to_string(&mut text)
+                .map_err(|e| ParseError::new(1, 1, e.to_string()))?;
+            Ok(text)
+        }
+
+        fn part_1(input: &Self::Input) -> usize {
+            input.trim_end().len()
+        }
+
+        fn part_2(input: &Self::Input) -> String {
+            input.trim_end().to_uppercase()
+        }
+    }
+
+    const ANSWERS: &str = "\
+[day-1]
+part-1 = 3
+part-2 = \"ABC\"
+
+[day-2]
+part-1 = 4
+";
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = format!("aoc-fixtures-{}-{}", name, std::process::id());
+        let dir = std::env::temp_dir().join(dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn checks_every_input() {
+        let days = (1..=4).map(Day::new::<Length>).collect::<Vec<_>>();
+        let dir = temp_dir("inputs");
+        fs::write(dir.join("day-01.txt"), "abc\n").unwrap();
+        fs::write(dir.join("day-02.txt"), "hello").unwrap();
+        fs::write(dir.join("day-04.txt"), "unrecorded").unwrap();
+        fs::write(dir.join(ANSWERS_FILE), ANSWERS).unwrap();
+        let result = check(&days, &dir);
+        fs::remove_file(dir.join("day-02.txt")).unwrap();
+        fs::remove_file(dir.join("day-04.txt")).unwrap();
+        let passing = check(&days, &dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let errors = result.unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(
+            &errors[0],
+            FixtureError::Mismatch { part: 1, expected, actual, .. } if expected == "4" && actual == "5"
+        ));
+        assert!(matches!(&errors[1], FixtureError::NoAnswers(_, 4)));
+        assert_eq!(passing.unwrap(), 2);
+    }
+
+    #[test]
+    fn empty_directories_are_errors() {
+        let days = [Day::new::<Length>(1)];
+        let dir = temp_dir("empty");
+        let result = check(&days, &dir);
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(matches!(&result.unwrap_err()[..], [FixtureError::NoInputs(_)]));
+        assert!(check(&days, "/nonexistent/aoc-fixtures").is_err());
+    }
+}
@@ -0,0 +1,256 @@
+//! A dense, rectangular 2D grid indexed by `(x, y)`.
+
+use std::io::BufRead;
+use std::ops::{Index, IndexMut};
+use std::fmt::{self, Display};
+
+use crate::{ParseError, Line};
+
+/// A position in a grid, as `(x, y)` with the origin in the top left.
+pub type Pos = (usize, usize);
+
+const OFFSETS_4: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+
+const OFFSETS_8: [(isize, isize); 8] = [
+    (1, 0), (-1, 0), (0, 1), (0, -1),
+    (1, 1), (1, -1), (-1, 1), (-1, -1)
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    /// A grid from its cells in row-major order.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+        Self { width, height, cells }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Self { width, height, cells }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// `pos` moved by `(dx, dy)`, if that is still inside the grid.
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours_4(&self, pos: Pos) -> impl Iterator<Item=Pos> + '_ {
+        OFFSETS_4.iter().filter_map(move |&d| self.offset(pos, d))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours_8(&self, pos: Pos) -> impl Iterator<Item=Pos> + '_ {
+        OFFSETS_8.iter().filter_map(move |&d| self.offset(pos, d))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item=Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=(Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item=(Pos, &mut T)> {
+        self.positions().zip(&mut self.cells)
+    }
+
+    pub fn values(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item=&T> {
+        assert!(x < self.width, "column {} is outside a grid {} wide", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item=impl Iterator<Item=&T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect()
+        }
+    }
+
+    /// Parses a grid with one cell per character, where `cell` maps a character
+    /// to a cell or `None` if it is not one of the `expected` characters.
+    pub fn from_lines(
+        lines: impl IntoIterator<Item=Result<Line, ParseError>>,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in lines {
+            let line = line?;
+            let mut row_width = 0;
+            for (i, c) in line.text.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    line.error_at(&line.text[i..], format!("expected {}, found `{}`", expected, c))
+                })?;
+                cells.push(value);
+                row_width += 1;
+            }
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    let reason = format!("expected a row of {} cells, found {}", width, row_width);
+                    return Err(line.error(reason));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Self::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn parse_chars(
+        input: impl BufRead,
+        cell: impl FnMut(char) -> Option<T>,
+        expected: &str
+    ) -> Result<Self, ParseError> {
+        Self::from_lines(crate::lines(input), cell, expected)
+    }
+}
+
+impl Grid<u32> {
+    /// Parses a grid of single decimal digits.
+    pub fn parse_digits(input: impl BufRead) -> Result<Self, ParseError> {
+        Self::parse_chars(input, |c| c.to_digit(10), "a digit")
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, width, height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, width, height))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_displays_digits() {
+        let grid = Grid::parse_digits("123\n456\n".as_bytes()).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = Grid::from_fn(3, 3, |(x, y)| x + y);
+        assert_eq!(grid.neighbours_4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours_8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let error = Grid::parse_digits("12\n3\n".as_bytes()).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = Grid::parse_digits("12\n3x\n".as_bytes()).unwrap_err();
+        assert_eq!((error.line, error.column, error.reason.as_str()), (2, 2, "expected a digit, found `x`"));
+    }
+}
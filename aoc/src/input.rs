@@ -0,0 +1,94 @@
+//! Finding puzzle inputs on disk.
+//!
+//! Inputs live in a local directory, `inputs/` unless `AOC_INPUTS` names
+//! another one, as `day-NN.txt` with the day zero-padded to two digits. Inputs
+//! are never downloaded; save them there once and every command finds them.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// The environment variable overriding the inputs directory.
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+
+pub const DEFAULT_INPUTS_DIR: &str = "inputs";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf)
+}
+
+impl Source {
+    /// `-` is stdin; anything else is a file.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Self::Stdin,
+            _ => Self::File(arg.into())
+        }
+    }
+
+    pub fn read(&self) -> Result<Vec<u8>, String> {
+        let mut input = Vec::new();
+        match self {
+            Self::File(path) => {
+                input = std::fs::read(path)
+                    .map_err(|e| format!("could not read `{}`: {}", path.display(), e))?;
+            }
+            Self::Stdin => {
+                std::io::stdin()
+                    .lock()
+                    .read_to_end(&mut input)
+                    .map_err(|e| format!("could not read stdin: {}", e))?;
+            }
+        }
+        Ok(input)
+    }
+}
+
+/// The inputs directory: `dir` if given, then `AOC_INPUTS`, then `inputs`.
+pub fn inputs_dir(dir: Option<&Path>) -> PathBuf {
+    match dir {
+        Some(dir) => dir.to_owned(),
+        None => std::env::var_os(INPUTS_ENV)
+            .filter(|dir| !dir.is_empty())
+            .map_or_else(|| DEFAULT_INPUTS_DIR.into(), PathBuf::from)
+    }
+}
+
+pub fn day_path(dir: &Path, day: u32) -> PathBuf {
+    dir.join(format!("day-{:02}.txt", day))
+}
+
+/// Finds the input for `day` in `dir`, naming the expected file if it is missing.
+pub fn find(dir: &Path, day: u32) -> Result<Source, String> {
+    let path = day_path(dir, day);
+    if path.is_file() {
+        Ok(Source::File(path))
+    } else {
+        Err(format!("no input for day {}, expected `{}` (or pass `--input -` for stdin)", day, path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_padded_day_files() {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("day-07.txt"), "16,1,2\n").unwrap();
+        let found = find(&dir, 7);
+        let missing = find(&dir, 8);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(found, Ok(Source::File(dir.join("day-07.txt"))));
+        assert!(missing.unwrap_err().contains("day-08.txt"));
+    }
+
+    #[test]
+    fn dash_is_stdin() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(Source::from_arg("day-1.txt"), Source::File("day-1.txt".into()));
+    }
+}
@@ -1,14 +1,52 @@
+use std::io::BufRead;
+use std::fmt::Display;
+
+mod parse;
+pub mod answers;
+pub mod bench;
+pub mod fixtures;
+pub mod grid;
+pub mod input;
+pub mod ocr;
+pub mod runner;
+pub mod search;
+
+pub use parse::{ParseError, Line, Lines, lines};
+
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError>;
+
+    fn part_1(input: &Self::Input) -> Self::Answer1;
+
+    fn part_2(input: &Self::Input) -> Self::Answer2;
+}
+
 #[macro_export]
 macro_rules! main {
-    () => {
+    ($($day:literal => $solution:path),* $(,)?) => {
+        fn days() -> Vec<$crate::runner::Day> {
+            vec![$(
+                $crate::runner::Day::new::<$solution>($day)
+            ),*]
+        }
+
         fn main() {
-            let stdin = std::io::stdin();
-            let input = stdin.lock();
-            match std::env::args().skip(1).next().as_deref() {
-                Some("1") => println!("{}", part_1(input)),
-                Some("2") => println!("{}", part_2(input)),
-                _ => eprintln!("Expected AoC part as argument (1 or 2)")
-            }
+            $crate::runner::main(&days());
+        }
+
+        #[cfg(test)]
+        #[test]
+        #[ignore = "checks the saved puzzle inputs; run with `--ignored`"]
+        fn inputs_match_answers() {
+            // Tests run in the runner's own directory, but the inputs are
+            // relative to the workspace root like every other command.
+            let root = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+            let inputs = root.join($crate::input::inputs_dir(None));
+            $crate::fixtures::assert_fixtures(&days(), inputs);
         }
     }
 }
@@ -0,0 +1,144 @@
+//! Reading letters drawn in the 6 pixel high Advent of Code font.
+//!
+//! Glyphs are separated by at least one blank column, so they are recognised
+//! by their shape alone and may be any width (most are 4, `Y` is 5).
+
+use std::fmt::{self, Display};
+
+const HEIGHT: usize = 6;
+
+const GLYPHS: &[(char, [&str; HEIGHT])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"])
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    Empty,
+    /// The lit pixels span this many rows instead of 6.
+    Height(usize),
+    /// The glyph starting at `column` is not a known letter.
+    UnknownGlyph {
+        column: usize,
+        glyph: String
+    }
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no lit pixels"),
+            Self::Height(height) => write!(f, "glyphs are {} rows high, expected {}", height, HEIGHT),
+            Self::UnknownGlyph { column, glyph } => {
+                write!(f, "unknown glyph at column {}:\n{}", column, glyph)
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+fn lit(row: &[bool], x: usize) -> bool {
+    row.get(x) == Some(&true)
+}
+
+fn glyph_matches(pattern: &[&str; HEIGHT], rows: &[&[bool]], columns: std::ops::Range<usize>) -> bool {
+    pattern.iter().zip(rows).all(|(pattern, row)| {
+        pattern.len() == columns.len()
+            && pattern.bytes().zip(columns.clone()).all(|(p, x)| (p == b'#') == lit(row, x))
+    })
+}
+
+/// Reads the letters in a grid of pixels, given as rows. Blank rows above and
+/// below the letters are ignored.
+pub fn recognize_rows<R: AsRef<[bool]>>(rows: &[R]) -> Result<String, OcrError> {
+    let lit_row = |row: &R| row.as_ref().contains(&true);
+    let top = rows.iter().position(lit_row).ok_or(OcrError::Empty)?;
+    let bottom = rows.iter().rposition(lit_row).unwrap();
+    let rows = rows[top..=bottom].iter().map(AsRef::as_ref).collect::<Vec<_>>();
+    if rows.len() != HEIGHT {
+        return Err(OcrError::Height(rows.len()));
+    }
+    let width = rows.iter().map(|row| row.len()).max().unwrap();
+    let lit_column = |x: usize| rows.iter().any(|row| lit(row, x));
+
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < width {
+        if !lit_column(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < width && lit_column(x) {
+            x += 1;
+        }
+        let letter = GLYPHS
+            .iter()
+            .find(|(_, pattern)| glyph_matches(pattern, &rows, start..x))
+            .map(|&(letter, _)| letter);
+        match letter {
+            Some(letter) => letters.push(letter),
+            None => {
+                let glyph = rows
+                    .iter()
+                    .map(|row| (start..x).map(|x| if lit(row, x) { '#' } else { '.' }).collect())
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                return Err(OcrError::UnknownGlyph { column: start, glyph });
+            }
+        }
+    }
+    Ok(letters)
+}
+
+/// Reads the letters drawn with `#` (or `█`) in `text`; any other character is blank.
+pub fn recognize(text: &str) -> Result<String, OcrError> {
+    let rows = text
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#' || c == '█').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    recognize_rows(&rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_letters() {
+        let text = "\
+.##..###..####.#...#
+#..#.#..#....#.#...#
+#..#.#..#...#...#.#.
+####.###...#.....#..
+#..#.#....#......#..
+#..#.#....####...#..
+";
+        assert_eq!(recognize(text), Ok("APZY".to_owned()));
+    }
+
+    #[test]
+    fn reports_unknown_glyphs() {
+        let text = "#####\n#   #\n#   #\n#   #\n#   #\n#####\n";
+        assert!(matches!(recognize(text), Err(OcrError::UnknownGlyph { column: 0, .. })));
+        assert_eq!(recognize("#\n#\n"), Err(OcrError::Height(2)));
+        assert_eq!(recognize("   \n"), Err(OcrError::Empty));
+    }
+}
@@ -0,0 +1,120 @@
+use std::io::BufRead;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub reason: String
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, reason: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            reason: reason.into()
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.reason)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone)]
+pub struct Line {
+    pub number: usize,
+    pub text: String
+}
+
+impl Line {
+    fn column_of(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        if offset <= self.text.len() && self.text.is_char_boundary(offset) {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+
+    /// An error pointing at `part`, which should be a slice of this line.
+    pub fn error_at(&self, part: &str, reason: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column_of(part), reason)
+    }
+
+    /// An error pointing at the start of this line.
+    pub fn error(&self, reason: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, 1, reason)
+    }
+
+    /// An error pointing just past the end of this line.
+    pub fn error_at_end(&self, reason: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.text.chars().count() + 1, reason)
+    }
+
+    pub fn split_once<'a>(&self, s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter).ok_or_else(|| {
+            let end = &s[s.len()..];
+            self.error_at(end, format!("expected `{}`", delimiter))
+        })
+    }
+
+    pub fn strip_prefix<'a>(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error_at(s, format!("expected `{}`", prefix)))
+    }
+
+    pub fn parse<T: FromStr>(&self, s: &str) -> Result<T, ParseError>
+    where
+        T::Err: Display
+    {
+        s.parse()
+            .map_err(|e| self.error_at(s, format!("invalid value `{}`: {}", s, e)))
+    }
+}
+
+pub struct Lines<R> {
+    lines: std::io::Lines<R>,
+    number: usize
+}
+
+impl<R: BufRead> Lines<R> {
+    /// The next line, or an error naming `what` was expected if the input ended.
+    pub fn expect(&mut self, what: &str) -> Result<Line, ParseError> {
+        match self.next() {
+            Some(line) => line,
+            None => Err(ParseError::new(
+                self.number + 1,
+                1,
+                format!("expected {}, found end of input", what)
+            ))
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = Result<Line, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.lines.next()?;
+        self.number += 1;
+        Some(match text {
+            Ok(text) => Ok(Line { number: self.number, text }),
+            Err(e) => Err(ParseError::new(self.number, 1, e.to_string()))
+        })
+    }
+}
+
+pub fn lines<R: BufRead>(input: R) -> Lines<R> {
+    Lines {
+        lines: input.lines(),
+        number: 0
+    }
+}
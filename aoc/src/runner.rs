@@ -0,0 +1,569 @@
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use crate::{Solution, ParseError};
+use crate::answers::{Answers, Verdict, ANSWERS_FILE};
+use crate::bench::{self, Stats};
+use crate::input::{self, Source};
+
+pub trait Parsed {
+    fn part_1(&self) -> String;
+
+    fn part_2(&self) -> String;
+
+    fn part(&self, part: u32) -> String {
+        match part {
+            1 => self.part_1(),
+            _ => self.part_2()
+        }
+    }
+}
+
+struct ParsedInput<S: Solution>(S::Input, PhantomData<S>);
+
+impl<S: Solution> Parsed for ParsedInput<S> {
+    fn part_1(&self) -> String {
+        S::part_1(&self.0).to_string()
+    }
+
+    fn part_2(&self) -> String {
+        S::part_2(&self.0).to_string()
+    }
+}
+
+pub type ParseFn = fn(&mut dyn BufRead) -> Result<Box<dyn Parsed>, ParseError>;
+
+pub struct Day {
+    pub day: u32,
+    pub parse: ParseFn,
+    /// The names of `Answer1` and `Answer2`, without their module paths.
+    pub answer_types: [&'static str; 2]
+}
+
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    match name.find('<') {
+        Some(_) => name,
+        None => name.rsplit("::").next().unwrap_or(name)
+    }
+}
+
+impl Day {
+    pub fn new<S: Solution + 'static>(day: u32) -> Self
+    where
+        S::Input: 'static
+    {
+        Self {
+            day,
+            parse: |input| {
+                let input = S::parse(input)?;
+                Ok(Box::new(ParsedInput::<S>(input, PhantomData)))
+            },
+            answer_types: [short_type_name::<S::Answer1>(), short_type_name::<S::Answer2>()]
+        }
+    }
+}
+
+const USAGE: &str = "\
+Usage:
+    aoc run <day> <part> [--input <file> | --inputs <dir>] [--time] [--format <format>]
+            [--check | --record] [--answers <file>]
+    aoc run --all [--inputs <dir>] [--time] [--format <format>] [--check | --record] [--answers <file>]
+    aoc bench <day> [<part>] [--input <file> | --inputs <dir>] [--iterations <n>]
+    aoc bench --all [--inputs <dir>] [--iterations <n>]
+
+Inputs are read from `<dir>/day-NN.txt`, where `<dir>` defaults to $AOC_INPUTS
+or `inputs`. Pass `--input -` to read stdin instead.
+
+`--time` prints how long parsing and each part took on stderr. `--format json`
+prints one JSON object per answer instead of text.";
+
+const DEFAULT_ITERATIONS: usize = 20;
+
+enum Mode {
+    Run,
+    Bench
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum AnswerMode {
+    Print,
+    Check,
+    Record
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json
+}
+
+enum Target {
+    Day {
+        day: u32,
+        part: Option<u32>,
+        input: Option<Source>
+    },
+    All
+}
+
+struct Command {
+    mode: Mode,
+    target: Target,
+    inputs: PathBuf,
+    time: bool,
+    format: Format,
+    iterations: usize,
+    answer_mode: AnswerMode,
+    answers: Option<PathBuf>
+}
+
+fn flag_value(args: &mut impl Iterator<Item=String>, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("expected a value after `{}`", flag))
+}
+
+fn parse_number<T: std::str::FromStr>(arg: &str, what: &str) -> Result<T, String> {
+    arg.parse().map_err(|_| format!("invalid {} `{}`", what, arg))
+}
+
+fn parse_args(mut args: impl Iterator<Item=String>) -> Result<Command, String> {
+    let mode = match args.next().as_deref() {
+        Some("run") => Mode::Run,
+        Some("bench") => Mode::Bench,
+        Some(command) => return Err(format!("unknown command `{}`", command)),
+        None => return Err("expected a command".to_owned())
+    };
+    let mut positional = Vec::new();
+    let mut all = false;
+    let mut input = None;
+    let mut inputs: Option<PathBuf> = None;
+    let mut time = false;
+    let mut format = None;
+    let mut iterations = None;
+    let mut answer_mode = AnswerMode::Print;
+    let mut answers = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--input" => input = Some(Source::from_arg(&flag_value(&mut args, "--input")?)),
+            "--inputs" => inputs = Some(flag_value(&mut args, "--inputs")?.into()),
+            "--time" => time = true,
+            "--format" => {
+                format = Some(match flag_value(&mut args, "--format")?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    other => return Err(format!("unknown format `{}` (expected text or json)", other))
+                });
+            }
+            "--check" | "--record" if answer_mode != AnswerMode::Print => {
+                return Err("`--check` and `--record` cannot be combined".to_owned());
+            }
+            "--check" => answer_mode = AnswerMode::Check,
+            "--record" => answer_mode = AnswerMode::Record,
+            "--answers" => answers = Some(flag_value(&mut args, "--answers")?.into()),
+            "--iterations" => {
+                let n = parse_number(&flag_value(&mut args, "--iterations")?, "iteration count")?;
+                if n == 0 {
+                    return Err("`--iterations` must be at least 1".to_owned());
+                }
+                iterations = Some(n);
+            }
+            _ if arg.starts_with("--") => return Err(format!("unknown option `{}`", arg)),
+            _ => positional.push(arg)
+        }
+    }
+    match mode {
+        Mode::Run if iterations.is_some() => {
+            return Err("`--iterations` is only valid with `bench`".to_owned());
+        }
+        Mode::Bench if time => return Err("`--time` is only valid with `run`".to_owned()),
+        Mode::Bench if answer_mode != AnswerMode::Print || answers.is_some() => {
+            return Err("`--check`, `--record` and `--answers` are only valid with `run`".to_owned());
+        }
+        Mode::Bench if format.is_some() => return Err("`--format` is only valid with `run`".to_owned()),
+        _ => {}
+    }
+    let format = format.unwrap_or(Format::Text);
+    let iterations = iterations.unwrap_or(DEFAULT_ITERATIONS);
+    if input.is_some() && inputs.is_some() {
+        return Err("`--input` and `--inputs` cannot be combined".to_owned());
+    }
+    let inputs = input::inputs_dir(inputs.as_deref());
+    if all {
+        if !positional.is_empty() || input.is_some() {
+            return Err("`--all` takes no day, part or `--input`".to_owned());
+        }
+        let target = Target::All;
+        return Ok(Command { mode, target, inputs, time, format, iterations, answer_mode, answers });
+    }
+    let (day, part) = match (&mode, positional.as_slice()) {
+        (_, [day, part]) => (parse_number(day, "day")?, Some(parse_number(part, "part")?)),
+        (Mode::Bench, [day]) => (parse_number(day, "day")?, None),
+        (Mode::Run, _) => return Err("expected a day and a part".to_owned()),
+        (Mode::Bench, _) => return Err("expected a day and an optional part".to_owned())
+    };
+    if let Some(part) = part {
+        if !(1..=2).contains(&part) {
+            return Err(format!("invalid part `{}` (expected 1 or 2)", part));
+        }
+    }
+    let target = Target::Day { day, part, input };
+    Ok(Command { mode, target, inputs, time, format, iterations, answer_mode, answers })
+}
+
+fn read_input(inputs: &Path, day: u32, input: Option<&Source>) -> Result<Vec<u8>, String> {
+    match input {
+        Some(source) => source.read(),
+        None => input::find(inputs, day)?.read()
+    }
+}
+
+fn parse_input(solution: &Day, input: &[u8]) -> Result<Box<dyn Parsed>, String> {
+    (solution.parse)(&mut &*input).map_err(|e| format!("failed to parse input: {}", e))
+}
+
+fn find_day(days: &[Day], day: u32) -> Result<&Day, String> {
+    days.iter()
+        .find(|d| d.day == day)
+        .ok_or_else(|| format!("no solution registered for day {}", day))
+}
+
+fn parts(part: Option<u32>) -> Vec<u32> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2]
+    }
+}
+
+/// The accepted answers, checked against or updated with each computed answer.
+struct AnswerBook {
+    mode: AnswerMode,
+    path: PathBuf,
+    answers: Answers,
+    failed: usize,
+    recorded: usize
+}
+
+impl AnswerBook {
+    fn open(mode: AnswerMode, path: PathBuf) -> Result<Self, String> {
+        let answers = match mode {
+            AnswerMode::Print => Answers::default(),
+            _ => Answers::load(&path)?
+        };
+        Ok(Self { mode, path, answers, failed: 0, recorded: 0 })
+    }
+
+    fn handle(&mut self, day: u32, part: u32, answer: &str) -> Option<Verdict> {
+        match self.mode {
+            AnswerMode::Print => None,
+            AnswerMode::Check => {
+                let verdict = self.answers.check(day, part, answer);
+                if matches!(verdict, Verdict::Fail { .. }) {
+                    self.failed += 1;
+                }
+                Some(verdict)
+            }
+            AnswerMode::Record => {
+                self.answers.insert(day, part, answer);
+                self.recorded += 1;
+                None
+            }
+        }
+    }
+
+    fn finish(self) -> Result<(), String> {
+        if self.mode == AnswerMode::Record {
+            self.answers.save(&self.path)?;
+            eprintln!("recorded {} answer(s) in `{}`", self.recorded, self.path.display());
+        }
+        if self.failed > 0 {
+            return Err(format!("{} answer(s) did not match `{}`", self.failed, self.path.display()));
+        }
+        Ok(())
+    }
+}
+
+fn answers_path(target: &Target, inputs: &Path) -> PathBuf {
+    match target {
+        Target::Day { input: Some(Source::File(input)), .. } => {
+            input.parent().unwrap_or(Path::new("")).join(ANSWERS_FILE)
+        }
+        _ => inputs.join(ANSWERS_FILE)
+    }
+}
+
+fn verdict_label(verdict: &Verdict) -> &'static str {
+    match verdict {
+        Verdict::Pass => "PASS",
+        Verdict::Fail { .. } => "FAIL",
+        Verdict::Unknown => "UNKNOWN"
+    }
+}
+
+fn format_expected(verdict: &Verdict) -> Option<String> {
+    match verdict {
+        Verdict::Fail { expected } if expected.contains('\n') => {
+            Some(format!("expected:\n{}", expected))
+        }
+        Verdict::Fail { expected } => Some(format!("expected {}", expected)),
+        _ => None
+    }
+}
+
+fn print_answer(day: u32, part: u32, answer: &str, verdict: Option<&Verdict>) {
+    let label = match verdict {
+        Some(verdict) => format!("Day {:>2} part {} [{}]", day, part, verdict_label(verdict)),
+        None => format!("Day {:>2} part {}", day, part)
+    };
+    if answer.contains('\n') {
+        println!("{}:\n{}", label, answer.trim_end());
+    } else {
+        println!("{}: {}", label, answer);
+    }
+    if let Some(expected) = verdict.and_then(format_expected) {
+        println!("{}", expected);
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            '\n' => json += "\\n",
+            '\r' => json += "\\r",
+            '\t' => json += "\\t",
+            c if c.is_control() => json += &format!("\\u{:04x}", c as u32),
+            c => json.push(c)
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn print_json_answer(
+    solution: &Day,
+    part: u32,
+    answer: &str,
+    parse_time: Duration,
+    part_time: Duration,
+    verdict: Option<&Verdict>
+) {
+    let mut json = format!(
+        "{{\"day\":{},\"part\":{},\"answer\":{},\"answer_type\":{},\"parse_ns\":{},\"elapsed_ns\":{}",
+        solution.day,
+        part,
+        json_string(answer.trim_end()),
+        json_string(solution.answer_types[part as usize - 1]),
+        parse_time.as_nanos(),
+        part_time.as_nanos()
+    );
+    if let Some(verdict) = verdict {
+        json += &format!(",\"check\":{}", json_string(verdict_label(verdict)));
+        if let Verdict::Fail { expected } = verdict {
+            json += &format!(",\"expected\":{}", json_string(expected));
+        }
+    }
+    json.push('}');
+    println!("{}", json);
+}
+
+fn print_json_error(day: u32, error: &str) {
+    println!("{{\"day\":{},\"error\":{}}}", day, json_string(error));
+}
+
+fn format_times(parse: Duration, parts: &[(u32, Duration)]) -> String {
+    let mut times = format!("parse {:.2?}", parse);
+    for (part, time) in parts {
+        times += &format!(", part {} {:.2?}", part, time);
+    }
+    times
+}
+
+fn print_stats(day: u32, label: &str, stats: &Stats) {
+    println!(
+        "Day {:>2} {:<6}  mean {:>10.2?}  median {:>10.2?}  std dev {:>10.2?}  ({} runs)",
+        day,
+        label,
+        stats.mean,
+        stats.median,
+        stats.std_dev,
+        stats.runs
+    );
+}
+
+fn run_day(
+    solution: &Day,
+    part: u32,
+    input: &[u8],
+    time: bool,
+    format: Format,
+    book: &mut AnswerBook
+) -> Result<(), String> {
+    let (parsed, parse_time) = bench::time(|| parse_input(solution, input));
+    let (answer, part_time) = bench::time(|| parsed.map(|parsed| parsed.part(part)));
+    let answer = answer?;
+    let verdict = book.handle(solution.day, part, &answer);
+    if format == Format::Json {
+        print_json_answer(solution, part, &answer, parse_time, part_time, verdict.as_ref());
+        return Ok(());
+    }
+    println!("{}", answer);
+    if time {
+        eprintln!("time: {}", format_times(parse_time, &[(part, part_time)]));
+    }
+    if let Some(verdict) = verdict {
+        match format_expected(&verdict) {
+            Some(expected) => eprintln!("check: {}, {}", verdict_label(&verdict), expected),
+            None => eprintln!("check: {}", verdict_label(&verdict))
+        }
+    }
+    Ok(())
+}
+
+fn timed_parse(solution: &Day, inputs: &Path) -> Result<(Box<dyn Parsed>, Duration), String> {
+    let input = read_input(inputs, solution.day, None)?;
+    let (parsed, parse_time) = bench::time(|| parse_input(solution, &input));
+    Ok((parsed?, parse_time))
+}
+
+fn run_all(
+    days: &[Day],
+    inputs: &Path,
+    time: bool,
+    format: Format,
+    book: &mut AnswerBook
+) -> Result<(), String> {
+    let mut failed = 0;
+    for solution in days {
+        match timed_parse(solution, inputs) {
+            Ok((parsed, parse_time)) => {
+                let mut part_times = Vec::new();
+                for part in 1..=2 {
+                    let (answer, part_time) = bench::time(|| parsed.part(part));
+                    let verdict = book.handle(solution.day, part, &answer);
+                    match format {
+                        Format::Text => print_answer(solution.day, part, &answer, verdict.as_ref()),
+                        Format::Json => print_json_answer(
+                            solution,
+                            part,
+                            &answer,
+                            parse_time,
+                            part_time,
+                            verdict.as_ref()
+                        )
+                    }
+                    part_times.push((part, part_time));
+                }
+                // Timings go to stderr, as for a single day, so answers stay
+                // alone on stdout.
+                if time && format == Format::Text {
+                    eprintln!("Day {:>2} time: {}", solution.day, format_times(parse_time, &part_times));
+                }
+            }
+            Err(e) => {
+                match format {
+                    Format::Text => eprintln!("Day {:>2}: {}", solution.day, e),
+                    Format::Json => print_json_error(solution.day, &e)
+                }
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        return Err(format!("{} day(s) could not be run", failed));
+    }
+    Ok(())
+}
+
+fn bench_day(solution: &Day, parts: &[u32], input: &[u8], iterations: usize) -> Result<(), String> {
+    let parsed = parse_input(solution, input)?;
+    let stats = bench::measure(iterations, || parse_input(solution, input));
+    print_stats(solution.day, "parse", &stats);
+    for &part in parts {
+        let stats = bench::measure(iterations, || parsed.part(part));
+        print_stats(solution.day, &format!("part {}", part), &stats);
+    }
+    Ok(())
+}
+
+fn bench_all(days: &[Day], inputs: &Path, iterations: usize) -> Result<(), String> {
+    let mut failed = 0;
+    for solution in days {
+        let result = read_input(inputs, solution.day, None)
+            .and_then(|input| bench_day(solution, &[1, 2], &input, iterations));
+        if let Err(e) = result {
+            eprintln!("Day {:>2}: {}", solution.day, e);
+            failed += 1;
+        }
+    }
+    if failed > 0 {
+        return Err(format!("{} day(s) could not be benchmarked", failed));
+    }
+    Ok(())
+}
+
+fn execute(days: &[Day], command: Command) -> Result<(), String> {
+    let inputs = command.inputs;
+    let answers = command.answers.unwrap_or_else(|| answers_path(&command.target, &inputs));
+    match (command.mode, command.target) {
+        (Mode::Run, Target::Day { day, part, input }) => {
+            let solution = find_day(days, day)?;
+            let mut book = AnswerBook::open(command.answer_mode, answers)?;
+            let part = part.unwrap_or(1);
+            let result = read_input(&inputs, day, input.as_ref()).and_then(|input| {
+                run_day(solution, part, &input, command.time, command.format, &mut book)
+            });
+            if let (Err(e), Format::Json) = (&result, command.format) {
+                print_json_error(day, e);
+            }
+            result?;
+            book.finish()
+        }
+        (Mode::Run, Target::All) => {
+            let mut book = AnswerBook::open(command.answer_mode, answers)?;
+            let result = run_all(days, &inputs, command.time, command.format, &mut book);
+            book.finish().and(result)
+        }
+        (Mode::Bench, Target::Day { day, part, input }) => {
+            let solution = find_day(days, day)?;
+            let input = read_input(&inputs, day, input.as_ref())?;
+            bench_day(solution, &parts(part), &input, command.iterations)
+        }
+        (Mode::Bench, Target::All) => bench_all(days, &inputs, command.iterations)
+    }
+}
+
+pub fn main(days: &[Day]) {
+    let command = match parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            std::process::exit(2);
+        }
+    };
+    if let Err(e) = execute(days, command) {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_json_strings() {
+        assert_eq!(json_string("# #\n\"a\"\\"), r##""# #\n\"a\"\\""##);
+        assert_eq!(json_string("\u{1}"), r#""\u0001""#);
+    }
+
+    #[test]
+    fn names_answer_types() {
+        assert_eq!(short_type_name::<u64>(), "u64");
+        assert_eq!(short_type_name::<String>(), "String");
+    }
+}
@@ -0,0 +1,300 @@
+//! Graph searches over nodes produced on demand by a successor function.
+//!
+//! Every search starts at one node and stops at the first node for which
+//! `is_goal` returns true, so the graph never has to be built up front.
+
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::collections::hash_map::Entry;
+use std::cmp::Reverse;
+use std::hash::Hash;
+use std::ops::Add;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult<N, C> {
+    /// The total cost of `path`; for the unweighted searches, its number of steps.
+    pub cost: C,
+    /// Every node from the start to the goal, both included.
+    pub path: Vec<N>,
+    /// How many nodes were taken off the frontier before the goal was reached.
+    pub expanded: usize
+}
+
+struct Visited<N, C> {
+    node: N,
+    parent: Option<usize>,
+    cost: C
+}
+
+fn reconstruct_path<N: Clone, C>(visited: &[Visited<N, C>], mut i: usize) -> Vec<N> {
+    let mut path = vec![visited[i].node.clone()];
+    while let Some(parent) = visited[i].parent {
+        path.push(visited[parent].node.clone());
+        i = parent;
+    }
+    path.reverse();
+    path
+}
+
+/// The cheapest path from `start` to a goal, where `successors` gives each
+/// neighbour of a node with the cost of moving to it. The `heuristic` must
+/// never overestimate the remaining cost, or the path may not be the cheapest.
+/// It need not be consistent: a node reached more cheaply after it was
+/// expanded is expanded again.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool
+) -> Option<SearchResult<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output=C> + Default,
+    I: IntoIterator<Item=(N, C)>
+{
+    let mut index = HashMap::new();
+    let mut visited = Vec::new();
+    let mut closed = Vec::new();
+    let mut frontier = BinaryHeap::new();
+    let mut expanded = 0;
+    frontier.push(Reverse((heuristic(&start), 0)));
+    index.insert(start.clone(), 0);
+    visited.push(Visited { node: start, parent: None, cost: C::default() });
+    closed.push(false);
+    while let Some(Reverse((_, i))) = frontier.pop() {
+        if closed[i] {
+            continue;
+        }
+        closed[i] = true;
+        expanded += 1;
+        let node = visited[i].node.clone();
+        if is_goal(&node) {
+            let path = reconstruct_path(&visited, i);
+            return Some(SearchResult { cost: visited[i].cost, path, expanded });
+        }
+        for (next, step) in successors(&node) {
+            let cost = visited[i].cost + step;
+            let j = match index.entry(next) {
+                Entry::Occupied(entry) => {
+                    let j = *entry.get();
+                    if visited[j].cost <= cost {
+                        continue;
+                    }
+                    visited[j].parent = Some(i);
+                    visited[j].cost = cost;
+                    closed[j] = false;
+                    j
+                }
+                Entry::Vacant(entry) => {
+                    let j = visited.len();
+                    visited.push(Visited { node: entry.key().clone(), parent: Some(i), cost });
+                    closed.push(false);
+                    entry.insert(j);
+                    j
+                }
+            };
+            frontier.push(Reverse((cost + heuristic(&visited[j].node), j)));
+        }
+    }
+    None
+}
+
+/// Marks a node with no parent in [`astar_indexed`].
+const NO_PARENT: u32 = u32::MAX;
+
+/// [`astar`] over the nodes `0..len`, keeping the search state in arrays
+/// indexed by node rather than a hash map, which suits large dense graphs
+/// such as grids. Panics if `len` does not fit in a `u32`.
+pub fn astar_indexed<C, I>(
+    len: usize,
+    start: usize,
+    mut successors: impl FnMut(usize) -> I,
+    mut heuristic: impl FnMut(usize) -> C,
+    mut is_goal: impl FnMut(usize) -> bool
+) -> Option<SearchResult<usize, C>>
+where
+    C: Copy + Ord + Add<Output=C> + Default,
+    I: IntoIterator<Item=(usize, C)>
+{
+    assert!(len < NO_PARENT as usize, "too many nodes to index");
+    let mut costs = vec![None; len];
+    let mut parents = vec![NO_PARENT; len];
+    let mut closed = vec![false; len];
+    let mut frontier = BinaryHeap::new();
+    let mut expanded = 0;
+    costs[start] = Some(C::default());
+    frontier.push(Reverse((heuristic(start), start as u32)));
+    while let Some(Reverse((_, i))) = frontier.pop() {
+        let i = i as usize;
+        if closed[i] {
+            continue;
+        }
+        closed[i] = true;
+        expanded += 1;
+        let cost = costs[i].expect("queued nodes have a cost");
+        if is_goal(i) {
+            let mut path = vec![i];
+            let mut node = i;
+            while parents[node] != NO_PARENT {
+                node = parents[node] as usize;
+                path.push(node);
+            }
+            path.reverse();
+            return Some(SearchResult { cost, path, expanded });
+        }
+        for (j, step) in successors(i) {
+            let next_cost = cost + step;
+            if costs[j].is_some_and(|c| c <= next_cost) {
+                continue;
+            }
+            costs[j] = Some(next_cost);
+            parents[j] = i as u32;
+            closed[j] = false;
+            frontier.push(Reverse((next_cost + heuristic(j), j as u32)));
+        }
+    }
+    None
+}
+
+/// The cheapest path from `start` to a goal; [`astar`] without a heuristic.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool
+) -> Option<SearchResult<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output=C> + Default,
+    I: IntoIterator<Item=(N, C)>
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// The path from `start` to a goal with the fewest steps.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool
+) -> Option<SearchResult<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item=N>
+{
+    let mut seen = HashSet::new();
+    let mut visited = Vec::new();
+    let mut frontier = VecDeque::new();
+    let mut expanded = 0;
+    seen.insert(start.clone());
+    visited.push(Visited { node: start, parent: None, cost: 0 });
+    frontier.push_back(0);
+    while let Some(i) = frontier.pop_front() {
+        expanded += 1;
+        let node = visited[i].node.clone();
+        if is_goal(&node) {
+            let path = reconstruct_path(&visited, i);
+            return Some(SearchResult { cost: visited[i].cost, path, expanded });
+        }
+        for next in successors(&node) {
+            if seen.insert(next.clone()) {
+                frontier.push_back(visited.len());
+                visited.push(Visited { node: next, parent: Some(i), cost: visited[i].cost + 1 });
+            }
+        }
+    }
+    None
+}
+
+/// Some path from `start` to a goal, found depth first. It is usually not the
+/// shortest, but the search only keeps one branch of the graph in its frontier.
+pub fn dfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool
+) -> Option<SearchResult<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item=N>
+{
+    let mut seen = HashSet::new();
+    let mut visited = Vec::new();
+    let mut frontier = vec![0];
+    let mut expanded = 0;
+    visited.push(Visited { node: start, parent: None, cost: 0 });
+    while let Some(i) = frontier.pop() {
+        let node = visited[i].node.clone();
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        expanded += 1;
+        if is_goal(&node) {
+            let path = reconstruct_path(&visited, i);
+            return Some(SearchResult { cost: visited[i].cost, path, expanded });
+        }
+        for next in successors(&node) {
+            if !seen.contains(&next) {
+                frontier.push(visited.len());
+                visited.push(Visited { node: next, parent: Some(i), cost: visited[i].cost + 1 });
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -1-> 1 -1-> 2 -1-> 3, with a direct 0 -5-> 3 edge and a dead end at 4.
+    fn weighted(&n: &u32) -> Vec<(u32, u32)> {
+        match n {
+            0 => vec![(1, 1), (3, 5), (4, 1)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![]
+        }
+    }
+
+    fn unweighted(n: &u32) -> Vec<u32> {
+        weighted(n).into_iter().map(|(n, _)| n).collect()
+    }
+
+    #[test]
+    fn weighted_searches_find_the_cheapest_path() {
+        let result = dijkstra(0, weighted, |&n| n == 3).unwrap();
+        assert_eq!((result.cost, result.path), (3, vec![0, 1, 2, 3]));
+        let result = astar(0, weighted, |&n| 3 - n.min(3), |&n| n == 3).unwrap();
+        assert_eq!((result.cost, result.path), (3, vec![0, 1, 2, 3]));
+        assert!(dijkstra(1, weighted, |&n| n == 0).is_none());
+        let indexed = |n: usize| weighted(&(n as u32)).into_iter().map(|(n, c)| (n as usize, c));
+        let result = astar_indexed(5, 0, indexed, |n| 3 - n.min(3) as u32, |n| n == 3).unwrap();
+        assert_eq!((result.cost, result.path), (3, vec![0, 1, 2, 3]));
+        assert!(astar_indexed(5, 1, indexed, |_| 0, |n| n == 0).is_none());
+    }
+
+    #[test]
+    fn astar_reopens_nodes_for_inconsistent_heuristics() {
+        // The heuristic overrates 1, so 3 is first reached through 2.
+        let graph = |&n: &u32| match n {
+            0 => vec![(1, 1), (2, 1)],
+            1 => vec![(3, 1)],
+            2 => vec![(3, 2)],
+            3 => vec![(4, 10)],
+            _ => vec![]
+        };
+        let heuristic = |&n: &u32| if n == 1 { 5 } else { 0 };
+        let result = astar(0, graph, heuristic, |&n| n == 4).unwrap();
+        assert_eq!((result.cost, result.path), (12, vec![0, 1, 3, 4]));
+        let indexed = |n: usize| graph(&(n as u32)).into_iter().map(|(n, c)| (n as usize, c));
+        let result = astar_indexed(5, 0, indexed, |n| heuristic(&(n as u32)), |n| n == 4).unwrap();
+        assert_eq!((result.cost, result.path), (12, vec![0, 1, 3, 4]));
+    }
+
+    #[test]
+    fn unweighted_searches_find_a_path() {
+        let result = bfs(0, unweighted, |&n| n == 3).unwrap();
+        assert_eq!((result.cost, result.path), (1, vec![0, 3]));
+        let result = dfs(0, unweighted, |&n| n == 3).unwrap();
+        assert_eq!(result.path.first(), Some(&0));
+        assert_eq!(result.path.last(), Some(&3));
+        assert_eq!(result.cost, result.path.len() - 1);
+    }
+}
@@ -0,0 +1,67 @@
+use std::io::BufRead;
+use aoc::ParseError;
+
+fn parse_inputs(input: impl BufRead) -> Result<Vec<i32>, ParseError> {
+    aoc::lines(input)
+        .map(|line| {
+            let line = line?;
+            line.parse(&line.text)
+        })
+        .collect()
+}
+
+pub struct Day1;
+
+impl aoc::Solution for Day1 {
+    type Input = Vec<i32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_inputs(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        input
+            .windows(2)
+            .filter(|d| d[0] < d[1])
+            .count() as u32
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        let summed = input
+            .windows(3)
+            .map(|d| d.iter().sum::<i32>())
+            .collect::<Vec<_>>();
+        summed
+            .windows(2)
+            .filter(|d| d[0] < d[1])
+            .count() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+199
+200
+208
+210
+200
+207
+240
+269
+260
+263
+";
+
+    #[test]
+    fn example() {
+        let input = Day1::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day1::part_1(&input), 7);
+        assert_eq!(Day1::part_2(&input), 5);
+    }
+}
@@ -0,0 +1,164 @@
+use std::io::BufRead;
+use std::iter::Peekable;
+use aoc::ParseError;
+
+#[derive(Debug)]
+#[allow(dead_code)]
+enum SyntaxError {
+    UnexpectedEnd,
+    InvalidClosingBracket(usize, char)
+}
+
+fn parse_lines(input: impl BufRead) -> Result<Vec<String>, ParseError> {
+    aoc::lines(input)
+        .map(|line| {
+            let line = line?;
+            if let Some(i) = line.text.find(|c| !matches!(c, '(' | ')' | '[' | ']' | '{' | '}' | '<' | '>')) {
+                return Err(line.error_at(&line.text[i..], "expected a bracket"));
+            }
+            Ok(line.text)
+        })
+        .collect()
+}
+
+fn closing_bracket(bracket: char) -> Option<char> {
+    Some(match bracket {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        _ => return None
+    })
+}
+
+fn is_bracket(bracket: char) -> bool {
+    closing_bracket(bracket).is_some()
+}
+
+fn parse_brackets<I: Iterator<Item=(usize, char)>>(input: &mut Peekable<I>) -> Result<(), SyntaxError> {
+    let (bracket_index, bracket) = input.next()
+        .ok_or(SyntaxError::UnexpectedEnd)?;
+    let expected = closing_bracket(bracket)
+        .ok_or(SyntaxError::InvalidClosingBracket(bracket_index, bracket))?;
+    while matches!(input.peek(), Some(&(_, c)) if is_bracket(c)) {
+        parse_brackets(input)?;
+    }
+    let (closing_index, closing) = input.next().ok_or(SyntaxError::UnexpectedEnd)?;
+    if closing != expected {
+        return Err(SyntaxError::InvalidClosingBracket(closing_index, closing));
+    }
+    Ok(())
+}
+
+fn complete_brackets<I: Iterator<Item=(usize, char)>>(input: &mut Peekable<I>, completion: &mut String) -> Result<(), SyntaxError> {
+    let (bracket_index, bracket) = match input.next() {
+        Some(c) => c,
+        None => return Ok(())
+    };
+    let expected = closing_bracket(bracket)
+        .ok_or(SyntaxError::InvalidClosingBracket(bracket_index, bracket))?;
+    while matches!(input.peek(), Some(&(_, c)) if is_bracket(c)) {
+        complete_brackets(input, completion)?;
+    }
+    if let Some((closing_index, closing)) = input.next() {
+        if closing != expected {
+            return Err(SyntaxError::InvalidClosingBracket(closing_index, closing));
+        }
+    } else {
+        completion.push(expected);
+    }
+    Ok(())
+}
+
+fn parse_chunks(line: &str) -> Result<(), SyntaxError> {
+    let mut line = line.chars().enumerate().peekable();
+    while line.peek().is_some() {
+        parse_brackets(&mut line)?;
+    }
+    Ok(())
+}
+
+fn complete_chunks(line: &str) -> Result<String, SyntaxError> {
+    let mut line = line.chars().enumerate().peekable();
+    let mut completion = String::new();
+    while line.peek().is_some() {
+        complete_brackets(&mut line, &mut completion)?;
+    }
+    Ok(completion)
+}
+
+pub struct Day10;
+
+impl aoc::Solution for Day10 {
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        let mut score = 0;
+        for line in input {
+            if let Err(SyntaxError::InvalidClosingBracket(_, bracket)) = parse_chunks(line) {
+                score += match bracket {
+                    ')' => 3,
+                    ']' => 57,
+                    '}' => 1197,
+                    '>' => 25137,
+                    _ => panic!()
+                };
+            }
+        }
+        score
+    }
+
+    fn part_2(input: &Self::Input) -> u64 {
+        let mut scores = Vec::new();
+        for line in input {
+            if let Ok(completion) = complete_chunks(line) {
+                let mut score = 0;
+                for bracket in completion.chars() {
+                    score *= 5;
+                    score += match bracket {
+                        ')' => 1,
+                        ']' => 2,
+                        '}' => 3,
+                        '>' => 4,
+                        _ => panic!()
+                    };
+                }
+                scores.push(score);
+            }
+        }
+        let middle = scores.len() / 2;
+        *scores.select_nth_unstable(middle).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]
+";
+
+    #[test]
+    fn example() {
+        let input = Day10::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day10::part_1(&input), 26397);
+        assert_eq!(Day10::part_2(&input), 288957);
+    }
+}
@@ -0,0 +1,79 @@
+use std::io::BufRead;
+use aoc::ParseError;
+use aoc::grid::Grid;
+
+fn step_grid(grid: &mut Grid<u32>) -> usize {
+    let mut flashes = 0;
+    for octopus in grid.values_mut() {
+        *octopus += 1;
+    }
+    loop {
+        let mut flashed = Vec::new();
+        for (pos, octopus) in grid.iter_mut() {
+            if *octopus > 9 {
+                *octopus = 0;
+                flashed.push(pos);
+            }
+        }
+        if flashed.is_empty() {
+            break;
+        }
+        flashes += flashed.len();
+        for pos in flashed {
+            for neighbour in grid.neighbours_8(pos).collect::<Vec<_>>() {
+                if grid[neighbour] != 0 {
+                    grid[neighbour] += 1;
+                }
+            }
+        }
+    }
+    flashes
+}
+
+pub struct Day11;
+
+impl aoc::Solution for Day11 {
+    type Input = Grid<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        Grid::parse_digits(input)
+    }
+
+    fn part_1(input: &Self::Input) -> usize {
+        let mut grid = input.clone();
+        (0..100).map(|_| step_grid(&mut grid)).sum()
+    }
+
+    fn part_2(input: &Self::Input) -> usize {
+        let mut grid = input.clone();
+        (1..).find(|_| step_grid(&mut grid) == grid.len()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+";
+
+    #[test]
+    fn example() {
+        let input = Day11::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day11::part_1(&input), 1656);
+        assert_eq!(Day11::part_2(&input), 195);
+    }
+}
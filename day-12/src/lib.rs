@@ -0,0 +1,143 @@
+use std::io::BufRead;
+use std::collections::HashMap;
+use aoc::ParseError;
+
+type CaveSystem = HashMap<String, Vec<String>>;
+
+fn parse_cave_system(input: impl BufRead) -> Result<CaveSystem, ParseError> {
+    let mut caves = CaveSystem::new();
+    let mut last = None;
+    for connection in aoc::lines(input) {
+        let connection = connection?;
+        let (a, b) = connection.split_once(&connection.text, "-")?;
+        caves.entry(a.to_owned()).or_default().push(b.to_owned());
+        caves.entry(b.to_owned()).or_default().push(a.to_owned());
+        last = Some(connection);
+    }
+    for cave in ["start", "end"] {
+        if !caves.contains_key(cave) {
+            let reason = format!("no connection to the `{}` cave", cave);
+            return Err(match &last {
+                Some(line) => line.error_at_end(reason),
+                None => ParseError::new(1, 1, reason)
+            });
+        }
+    }
+    Ok(caves)
+}
+
+fn unique_paths_to_end(
+    caves: &CaveSystem,
+    mut can_visit_cave: impl FnMut(&str, &mut Vec<String>) -> bool
+) -> u32 {
+    fn visit(
+        caves: &CaveSystem,
+        paths: &mut u32,
+        can_visit_cave: &mut impl FnMut(&str, &mut Vec<String>) -> bool,
+        path: &mut Vec<String>,
+        cave: &str
+    ) {
+        if !can_visit_cave(cave, path) {
+            return;
+        }
+        if cave == "end" {
+            *paths += 1;
+            return;
+        }
+        path.push(cave.to_owned());
+        for neighbour in caves.get(cave).unwrap() {
+            visit(caves, paths, can_visit_cave, path, neighbour);
+        }
+        path.pop();
+    }
+    let mut path = Vec::new();
+    let mut paths = 0;
+    visit(caves, &mut paths, &mut can_visit_cave, &mut path, "start");
+    paths
+}
+
+fn cave_is_small(cave: &str) -> bool {
+    cave.chars().all(|c| c.is_lowercase())
+}
+
+fn visit_budget(cave: &str, path: &[String]) -> u32 {
+    if cave == "start" {
+        return 1;
+    }
+    for (i, cave_a) in path.iter().enumerate() {
+        if !cave_is_small(cave_a) {
+            continue;
+        }
+        for (j, cave_b) in path.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            if cave_a == cave_b {
+                return 1;
+            }
+        }
+    }
+    2
+}
+
+pub struct Day12;
+
+impl aoc::Solution for Day12 {
+    type Input = CaveSystem;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_cave_system(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        unique_paths_to_end(input, |cave, path| {
+            !(cave_is_small(cave) && path.iter().any(|c| c.as_str() == cave))
+        })
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        unique_paths_to_end(input, |cave, path| {
+            if cave_is_small(cave) {
+                let visited = path.iter().filter(|c| c.as_str() == cave).count();
+                (visited as u32) < visit_budget(cave, path)
+            } else {
+                true
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end
+";
+
+    #[test]
+    fn example() {
+        let input = Day12::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day12::part_1(&input), 10);
+        assert_eq!(Day12::part_2(&input), 36);
+    }
+
+    #[test]
+    fn requires_start_and_end() {
+        let error = Day12::parse("start-A\nA-b\n".as_bytes()).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.reason, "no connection to the `end` cave");
+        let error = Day12::parse("".as_bytes()).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.reason, "no connection to the `start` cave");
+    }
+}
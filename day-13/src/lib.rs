@@ -0,0 +1,165 @@
+use std::io::BufRead;
+use std::collections::HashSet;
+use aoc::ParseError;
+
+type Point = (i32, i32);
+
+#[derive(Debug)]
+pub enum Fold {
+    X(i32),
+    Y(i32)
+}
+
+fn parse_input(input: impl BufRead) -> Result<(Vec<Point>, Vec<Fold>), ParseError> {
+    let mut lines = aoc::lines(input);
+    let mut points = Vec::new();
+    for line in &mut lines {
+        let line = line?;
+        if line.text.is_empty() {
+            break;
+        }
+        let (x, y) = line.split_once(&line.text, ",")?;
+        points.push((line.parse(x)?, line.parse(y)?));
+    }
+    let first = parse_fold(&lines.expect("a fold instruction")?)?;
+    let mut folds = vec![first];
+    for line in lines {
+        folds.push(parse_fold(&line?)?);
+    }
+    Ok((points, folds))
+}
+
+fn parse_fold(line: &aoc::Line) -> Result<Fold, ParseError> {
+    let (fold, n) = line.split_once(&line.text, "=")?;
+    let n = line.parse(n)?;
+    match fold {
+        "fold along x" => Ok(Fold::X(n)),
+        "fold along y" => Ok(Fold::Y(n)),
+        _ => Err(line.error_at(fold, format!("unknown fold `{}`", fold)))
+    }
+}
+
+pub struct Day13;
+
+impl aoc::Solution for Day13 {
+    type Input = (Vec<Point>, Vec<Fold>);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> usize {
+        let (points, folds) = input;
+        let mut points = points.clone();
+        for (px, py) in &mut points {
+            match *folds.first().unwrap() {
+                Fold::X(fx) => if *px > fx { *px = fx - (*px - fx) },
+                Fold::Y(fy) => if *py > fy { *py = fy - (*py - fy) },
+            }
+        }
+        points.into_iter().collect::<HashSet<_>>().len()
+    }
+
+    fn part_2(input: &Self::Input) -> String {
+        let (points, folds) = input;
+        let mut points = points.clone();
+        for fold in folds {
+            for (px, py) in &mut points {
+                match *fold {
+                    Fold::X(fx) => if *px > fx { *px = fx - (*px - fx) },
+                    Fold::Y(fy) => if *py > fy { *py = fy - (*py - fy) },
+                }
+            }
+        }
+        let points = points.into_iter().collect::<HashSet<_>>();
+        let w = *points.iter().map(|(x, _)| x).max().unwrap() + 1;
+        let h = *points.iter().map(|(_, y)| y).max().unwrap() + 1;
+        let mut output = String::new();
+        for y in 0..h {
+            for x in 0..w {
+                if points.contains(&(x, y)) {
+                    output.push('#');
+                } else {
+                    output.push(' ');
+                }
+            }
+            output.push('\n');
+        }
+        match aoc::ocr::recognize(&output) {
+            Ok(letters) => letters,
+            Err(e) => {
+                // The drawing is still the answer, but not in the usual form.
+                eprintln!("warning: could not read the folded letters: {}", e);
+                output
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5
+";
+
+    #[test]
+    fn example() {
+        let input = Day13::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day13::part_1(&input), 17);
+        assert_eq!(Day13::part_2(&input), "#####\n#   #\n#   #\n#   #\n#####\n");
+    }
+
+    #[test]
+    fn reads_folded_letters() {
+        let letters = "\
+#..#.###.
+#..#..#..
+####..#..
+#..#..#..
+#..#..#..
+#..#.###.
+";
+        let mut points = Vec::new();
+        for (y, row) in letters.lines().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                if c == '#' {
+                    // Mirror every dot to the far side of the fold line.
+                    points.push((20 - x as i32, y as i32));
+                }
+            }
+        }
+        assert_eq!(Day13::part_2(&(points, vec![Fold::X(10)])), "HI");
+    }
+
+    #[test]
+    fn requires_a_fold() {
+        let error = Day13::parse("6,10\n0,14\n\n".as_bytes()).unwrap_err();
+        assert_eq!(error.line, 4);
+        assert_eq!(error.reason, "expected a fold instruction, found end of input");
+    }
+}
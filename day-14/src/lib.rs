@@ -0,0 +1,138 @@
+use std::io::BufRead;
+use std::collections::HashMap;
+use aoc::ParseError;
+
+type Rules = HashMap<(char, char), char>;
+
+fn parse_template(input: impl BufRead) -> Result<(Vec<char>, Rules), ParseError> {
+    let mut lines = aoc::lines(input);
+    let template = lines.expect("a polymer template")?;
+    if template.text.is_empty() {
+        return Err(template.error("expected a polymer template"));
+    }
+    let init = template.text.chars().collect();
+    let separator = lines.expect("a blank line")?;
+    if !separator.text.is_empty() {
+        return Err(separator.error("expected a blank line"));
+    }
+    let rules = lines
+        .map(|line| {
+            let line = line?;
+            let (from, to) = line.split_once(&line.text, " -> ")?;
+            let mut from_chars = from.chars();
+            let pair = match (from_chars.next(), from_chars.next(), from_chars.next()) {
+                (Some(left), Some(right), None) => (left, right),
+                _ => return Err(line.error_at(from, "expected a pair of elements"))
+            };
+            let mut to_chars = to.chars();
+            let middle = match (to_chars.next(), to_chars.next()) {
+                (Some(middle), None) => middle,
+                _ => return Err(line.error_at(to, "expected a single element"))
+            };
+            Ok((pair, middle))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((init, rules))
+}
+
+type LetterCounts = HashMap<char, u64>;
+
+fn add_counts(counts: &mut LetterCounts, other: &LetterCounts) {
+    for (&c, &n) in other {
+        *counts.entry(c).or_default() += n;
+    }
+}
+
+fn letter_counts(
+    rules: &HashMap<(char, char), char>,
+    cache: &mut HashMap<((char, char), u8), LetterCounts>,
+    (left, right): (char, char),
+    depth: u8
+) -> LetterCounts {
+    let cache_key = ((left, right), depth);
+    let middle = match rules.get(&(left, right)) {
+        Some(&middle) if depth > 0 => middle,
+        _ => return [(left, 1)].into_iter().collect()
+    };
+    if let Some(counts) = cache.get(&cache_key) {
+        counts.clone()
+    } else {
+        let mut total = letter_counts(rules, cache, (left, middle), depth - 1);
+        let right = letter_counts(rules, cache, (middle, right), depth - 1);
+        add_counts(&mut total, &right);
+        cache.insert(cache_key, total.clone());
+        total
+    }
+}
+
+fn solve(polymer: &[char], rules: &HashMap<(char, char), char>, steps: u8) -> u64 {
+    let mut cache = HashMap::new();
+    let mut counts = LetterCounts::new();
+    for window in polymer.windows(2) {
+        let result = letter_counts(
+            rules,
+            &mut cache,
+            (window[0], window[1]),
+            steps
+        );
+        add_counts(&mut counts, &result);
+    }
+    *counts.entry(*polymer.last().unwrap()).or_default() += 1;
+    counts.values().max().unwrap() - counts.values().min().unwrap()
+}
+
+pub struct Day14;
+
+impl aoc::Solution for Day14 {
+    type Input = (Vec<char>, Rules);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_template(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u64 {
+        let (polymer, rules) = input;
+        solve(polymer, rules, 10)
+    }
+
+    fn part_2(input: &Self::Input) -> u64 {
+        let (polymer, rules) = input;
+        solve(polymer, rules, 40)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C
+";
+
+    #[test]
+    fn example() {
+        let input = Day14::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day14::part_1(&input), 1588);
+        assert_eq!(Day14::part_2(&input), 2188189693529);
+    }
+}
@@ -0,0 +1,54 @@
+//! Prints the lowest-risk route through a day 15 map, and optionally draws it
+//! as an image.
+//!
+//! Usage: cargo run -p day-15 --example render -- <input> [--tiles <n>] [--ppm <file>]
+//!
+//! `--tiles 5` renders the full map from part 2.
+
+use std::io::{BufReader, BufWriter};
+use std::fs::File;
+
+use aoc::Solution;
+use day_15::{Day15, RiskMap};
+
+fn render(map: &impl RiskMap, ppm: Option<String>) -> Result<(), String> {
+    let route = day_15::lowest_risk_path(map).ok_or("the map is empty")?;
+    print!("{}", day_15::render_ascii(map, &route.path));
+    println!("risk {}, {} cells, {} expanded", route.cost, route.path.len(), route.expanded);
+    if let Some(ppm) = ppm {
+        let file = File::create(&ppm).map_err(|e| format!("could not create `{}`: {}", ppm, e))?;
+        day_15::write_ppm(map, &route.path, BufWriter::new(file))
+            .map_err(|e| format!("could not write `{}`: {}", ppm, e))?;
+    }
+    Ok(())
+}
+
+fn run() -> Result<(), String> {
+    let mut args = std::env::args().skip(1);
+    let mut input = None;
+    let mut tiles = 1;
+    let mut ppm = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--tiles" => {
+                let n = args.next().ok_or("expected a number after `--tiles`")?;
+                tiles = n.parse().map_err(|_| format!("invalid tile count `{}`", n))?;
+            }
+            "--ppm" => ppm = Some(args.next().ok_or("expected a file after `--ppm`")?),
+            _ if arg.starts_with("--") => return Err(format!("unknown option `{}`", arg)),
+            _ => input = Some(arg)
+        }
+    }
+    let input = input.ok_or("expected an input file")?;
+    let file = File::open(&input).map_err(|e| format!("could not open `{}`: {}", input, e))?;
+    let grid = Day15::parse(BufReader::new(file)).map_err(|e| format!("{}: {}", input, e))?;
+    let map = day_15::tile_risk_map(&grid, tiles, tiles, 9).map_err(|e| e.to_string())?;
+    render(&map, ppm)
+}
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
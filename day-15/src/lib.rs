@@ -0,0 +1,279 @@
+use std::io::{self, BufRead, Write};
+use std::fmt::{self, Display};
+use aoc::ParseError;
+use aoc::grid::{Grid, Pos};
+use aoc::search::{self, SearchResult};
+
+/// A rectangular map of risk levels, each at least 1.
+pub trait RiskMap {
+    fn width(&self) -> usize;
+
+    fn height(&self) -> usize;
+
+    /// The risk of entering `pos`, which must be inside the map.
+    fn risk(&self, pos: Pos) -> u32;
+
+    fn max_risk(&self) -> u32;
+}
+
+impl RiskMap for Grid<u32> {
+    fn width(&self) -> usize {
+        Grid::width(self)
+    }
+
+    fn height(&self) -> usize {
+        Grid::height(self)
+    }
+
+    fn risk(&self, pos: Pos) -> u32 {
+        self[pos]
+    }
+
+    fn max_risk(&self) -> u32 {
+        self.values().copied().max().unwrap_or(1)
+    }
+}
+
+/// A grid repeated in a larger map, computed cell by cell as it is read.
+#[derive(Debug, Clone, Copy)]
+pub struct TiledMap<'a> {
+    grid: &'a Grid<u32>,
+    tiles_x: usize,
+    tiles_y: usize,
+    max_risk: u32
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TileError {
+    /// There are no risk levels to wrap around.
+    NoRiskLevels,
+    /// A cell of the grid is not between 1 and the maximum risk.
+    RiskOutOfRange { pos: Pos, risk: u32, max_risk: u32 }
+}
+
+impl Display for TileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoRiskLevels => write!(f, "the maximum risk must be at least 1"),
+            Self::RiskOutOfRange { pos, risk, max_risk } => {
+                write!(f, "the risk {} at {:?} is not between 1 and {}", risk, pos, max_risk)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TileError {}
+
+/// Repeats `grid` `tiles_x` times to the right and `tiles_y` times down. Every
+/// tile is one risk level higher than the one to its left or above, wrapping
+/// from `max_risk` back to 1.
+pub fn tile_risk_map(
+    grid: &Grid<u32>,
+    tiles_x: usize,
+    tiles_y: usize,
+    max_risk: u32
+) -> Result<TiledMap<'_>, TileError> {
+    if max_risk == 0 {
+        return Err(TileError::NoRiskLevels);
+    }
+    if let Some((pos, &risk)) = grid.iter().find(|&(_, &risk)| !(1..=max_risk).contains(&risk)) {
+        return Err(TileError::RiskOutOfRange { pos, risk, max_risk });
+    }
+    Ok(TiledMap { grid, tiles_x, tiles_y, max_risk })
+}
+
+impl RiskMap for TiledMap<'_> {
+    fn width(&self) -> usize {
+        self.grid.width() * self.tiles_x
+    }
+
+    fn height(&self) -> usize {
+        self.grid.height() * self.tiles_y
+    }
+
+    fn risk(&self, (x, y): Pos) -> u32 {
+        let (w, h) = (self.grid.width(), self.grid.height());
+        assert!(
+            x < w * self.tiles_x && y < h * self.tiles_y,
+            "{:?} is outside the tiled map",
+            (x, y)
+        );
+        let base = self.grid[(x % w, y % h)] as u64;
+        let increase = (x / w + y / h) as u64;
+        ((base + increase - 1) % self.max_risk as u64 + 1) as u32
+    }
+
+    fn max_risk(&self) -> u32 {
+        self.max_risk
+    }
+}
+
+fn neighbours(map: &impl RiskMap, (x, y): Pos) -> impl Iterator<Item=Pos> {
+    let (width, height) = (map.width(), map.height());
+    [(1, 0), (-1, 0), (0, 1), (0, -1)]
+        .into_iter()
+        .filter_map(move |(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
+        .filter(move |&(x, y)| x < width && y < height)
+}
+
+/// The lowest-risk route from the top left to the bottom right corner, where
+/// entering a cell costs its risk level.
+pub fn lowest_risk_path(map: &impl RiskMap) -> Option<SearchResult<Pos, u32>> {
+    let (width, height) = (map.width(), map.height());
+    let end = (width.checked_sub(1)?, height.checked_sub(1)?);
+    let pos = |i: usize| (i % width, i / width);
+    let successors = |i: usize| {
+        neighbours(map, pos(i)).map(move |(x, y)| (y * width + x, map.risk((x, y))))
+    };
+    // Every cell costs at least 1, so the Manhattan distance is admissible.
+    let distance = |i: usize| {
+        let (x, y) = pos(i);
+        (x.abs_diff(end.0) + y.abs_diff(end.1)) as u32
+    };
+    let result = search::astar_indexed(width * height, 0, successors, distance, |i| pos(i) == end)?;
+    let path = result.path.into_iter().map(pos).collect();
+    Some(SearchResult { cost: result.cost, path, expanded: result.expanded })
+}
+
+fn path_mask(map: &impl RiskMap, path: &[Pos]) -> Grid<bool> {
+    let mut mask = Grid::from_fn(map.width(), map.height(), |_| false);
+    for &pos in path {
+        mask[pos] = true;
+    }
+    mask
+}
+
+/// The map with every cell off `path` replaced by `.`.
+pub fn render_ascii(map: &impl RiskMap, path: &[Pos]) -> String {
+    let mask = path_mask(map, path);
+    Grid::from_fn(map.width(), map.height(), |pos| {
+        if mask[pos] {
+            char::from_digit(map.risk(pos), 10).unwrap_or('#')
+        } else {
+            '.'
+        }
+    })
+    .to_string()
+}
+
+/// Writes the map as a binary PPM image with one pixel per cell: darker
+/// cells are riskier, and `path` is drawn in red.
+pub fn write_ppm(map: &impl RiskMap, path: &[Pos], mut out: impl Write) -> io::Result<()> {
+    let mask = path_mask(map, path);
+    let max_risk = map.max_risk().max(1);
+    write!(out, "P6\n{} {}\n255\n", map.width(), map.height())?;
+    let mut pixels = Vec::with_capacity(mask.len() * 3);
+    for (pos, &on_path) in mask.iter() {
+        if on_path {
+            pixels.extend([255, 32, 32]);
+        } else {
+            let shade = 255 - (map.risk(pos).min(max_risk) * 223 / max_risk) as u8;
+            pixels.extend([shade; 3]);
+        }
+    }
+    out.write_all(&pixels)
+}
+
+pub struct Day15;
+
+impl aoc::Solution for Day15 {
+    type Input = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        Grid::parse_chars(input, |c| c.to_digit(10).filter(|&risk| risk > 0), "a risk level from 1 to 9")
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        lowest_risk_path(input).unwrap().cost
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        let map = tile_risk_map(input, 5, 5, 9).expect("parsing checks every risk is from 1 to 9");
+        lowest_risk_path(&map).unwrap().cost
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+";
+
+    #[test]
+    fn example() {
+        let input = Day15::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day15::part_1(&input), 40);
+        assert_eq!(Day15::part_2(&input), 315);
+    }
+
+    #[test]
+    fn heuristic_only_prunes() {
+        let grid = Day15::parse(EXAMPLE.as_bytes()).unwrap();
+        let end = (grid.width() - 1, grid.height() - 1);
+        let astar = lowest_risk_path(&grid).unwrap();
+        let dijkstra = search::dijkstra(
+            (0, 0),
+            |&pos| grid.neighbours_4(pos).map(|p| (p, grid[p])),
+            |&pos| pos == end
+        ).unwrap();
+        assert_eq!(astar.cost, dijkstra.cost);
+        assert!(astar.expanded <= dijkstra.expanded);
+    }
+
+    #[test]
+    fn renders_the_path() {
+        let grid = Grid::parse_digits("19\n11\n".as_bytes()).unwrap();
+        let path = lowest_risk_path(&grid).unwrap().path;
+        assert_eq!(path, [(0, 0), (0, 1), (1, 1)]);
+        assert_eq!(render_ascii(&grid, &path), "1.\n11\n");
+        let mut ppm = Vec::new();
+        write_ppm(&grid, &path, &mut ppm).unwrap();
+        assert!(ppm.starts_with(b"P6\n2 2\n255\n"));
+        assert_eq!(ppm.len(), b"P6\n2 2\n255\n".len() + 4 * 3);
+    }
+
+    #[test]
+    fn tiles_wrap_risk() {
+        let grid = Grid::parse_digits("8".as_bytes()).unwrap();
+        let map = tile_risk_map(&grid, 5, 5, 9).unwrap();
+        let risks = (0..5).map(|x| map.risk((x, 0))).collect::<Vec<_>>();
+        assert_eq!(risks, [8, 9, 1, 2, 3]);
+        assert_eq!(map.risk((4, 4)), 7);
+        let two = Grid::parse_digits("2".as_bytes()).unwrap();
+        assert_eq!(tile_risk_map(&two, 2, 1, 3).unwrap().risk((1, 0)), 3);
+    }
+
+    #[test]
+    fn rejects_risks_out_of_range() {
+        let grid = Grid::parse_digits("10".as_bytes()).unwrap();
+        let error = tile_risk_map(&grid, 5, 5, 9).unwrap_err();
+        assert_eq!(error, TileError::RiskOutOfRange { pos: (1, 0), risk: 0, max_risk: 9 });
+        assert_eq!(error.to_string(), "the risk 0 at (1, 0) is not between 1 and 9");
+        let one = Grid::parse_digits("1".as_bytes()).unwrap();
+        assert_eq!(tile_risk_map(&one, 5, 5, 0).unwrap_err(), TileError::NoRiskLevels);
+    }
+
+    #[test]
+    fn zero_tiles_make_an_empty_map() {
+        let grid = Grid::parse_digits("12\n34\n".as_bytes()).unwrap();
+        for (tiles_x, tiles_y) in [(0, 3), (3, 0), (0, 0)] {
+            let map = tile_risk_map(&grid, tiles_x, tiles_y, 9).unwrap();
+            assert_eq!((map.width(), map.height()), (2 * tiles_x, 2 * tiles_y));
+            assert!(lowest_risk_path(&map).is_none());
+        }
+    }
+}
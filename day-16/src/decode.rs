@@ -0,0 +1,182 @@
+//! Decoding packets from a stream of bits, with every error located by the
+//! offset of the bit where it was found.
+
+use std::fmt::{self, Display};
+
+use crate::{ArityError, OperatorKind, Packet, PacketBody, LITERAL_TYPE_ID};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeErrorKind {
+    /// The stream ended in the middle of this field.
+    Truncated(&'static str),
+    /// A sub-packet ran past the end of its operator's bit length, which is
+    /// the offset given here.
+    Overrun(usize),
+    /// A literal value too large for 64 bits.
+    LiteralTooLarge,
+    Arity(ArityError)
+}
+
+impl Display for DecodeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated(field) => write!(f, "the transmission ends inside the {}", field),
+            Self::Overrun(end) => write!(f, "sub-packet runs past the end of its operator at bit {}", end),
+            Self::LiteralTooLarge => write!(f, "literal value does not fit in 64 bits"),
+            Self::Arity(e) => write!(f, "{}", e)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeError {
+    /// The offset of the first bit of the offending field or packet.
+    pub offset: usize,
+    pub kind: DecodeErrorKind
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at bit {}", self.kind, self.offset)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+struct Reader<I> {
+    bits: I,
+    /// The offset of the next bit.
+    offset: usize,
+    /// The offset of every packet started so far, outermost first.
+    starts: Vec<usize>,
+    /// The end of the sub-packets being read, if their length is in bits.
+    end: Option<usize>
+}
+
+impl<I: Iterator<Item=bool>> Reader<I> {
+    fn error(&self, offset: usize, kind: DecodeErrorKind) -> DecodeError {
+        DecodeError { offset, kind }
+    }
+
+    fn number(&mut self, bits: usize, field: &'static str) -> Result<u64, DecodeError> {
+        let start = self.offset;
+        if let Some(end) = self.end.filter(|&end| start + bits > end) {
+            return Err(self.error(start, DecodeErrorKind::Overrun(end)));
+        }
+        let mut n = 0;
+        for _ in 0..bits {
+            let bit = self.bits.next().ok_or(self.error(start, DecodeErrorKind::Truncated(field)))?;
+            n = n << 1 | bit as u64;
+            self.offset += 1;
+        }
+        Ok(n)
+    }
+
+    fn packet(&mut self) -> Result<Packet, DecodeError> {
+        let start = self.offset;
+        self.starts.push(start);
+        let version = self.number(3, "version")?;
+        let id = self.number(3, "type ID")?;
+        let body = match id {
+            LITERAL_TYPE_ID => {
+                let mut n = 0u64;
+                loop {
+                    let start = self.offset;
+                    let group = self.number(5, "literal group")?;
+                    if n >> 60 != 0 {
+                        return Err(self.error(start, DecodeErrorKind::LiteralTooLarge));
+                    }
+                    n = n << 4 | group & 0xF;
+                    if group & 0x10 == 0 {
+                        break;
+                    }
+                }
+                PacketBody::Literal(n)
+            }
+            _ => {
+                // Every 3 bit type ID other than the literal one is an operator.
+                let operator = OperatorKind::from_type_id(id).unwrap();
+                let mut packets = Vec::new();
+                if self.number(1, "length type ID")? == 1 {
+                    let count = self.number(11, "sub-packet count")?;
+                    for _ in 0..count {
+                        packets.push(self.packet()?);
+                    }
+                } else {
+                    let field = self.offset;
+                    let len = self.number(15, "sub-packet length")? as usize;
+                    let end = self.offset + len;
+                    // Sub-packets have to fit inside every operator around them.
+                    if let Some(outer_end) = self.end.filter(|&outer_end| end > outer_end) {
+                        return Err(self.error(field, DecodeErrorKind::Overrun(outer_end)));
+                    }
+                    let outer = self.end.replace(end);
+                    while self.offset < end {
+                        packets.push(self.packet()?);
+                    }
+                    self.end = outer;
+                }
+                operator
+                    .check_arity(packets.len())
+                    .map_err(|e| self.error(start, DecodeErrorKind::Arity(e)))?;
+                PacketBody::Operator(operator, packets)
+            }
+        };
+        Ok(Packet { version, body })
+    }
+}
+
+/// Decodes a packet along with the offset of each packet in it, in the order
+/// they appear in the stream.
+pub(crate) fn decode(bits: impl Iterator<Item=bool>) -> Result<(Packet, Vec<usize>), DecodeError> {
+    let mut reader = Reader { bits, offset: 0, starts: Vec::new(), end: None };
+    let packet = reader.packet()?;
+    Ok((packet, reader.starts))
+}
+
+impl Packet {
+    /// Decodes the packet at the start of `bits`, ignoring whatever follows
+    /// it, and checks that every operator has a valid number of sub-packets.
+    pub fn try_parse(bits: impl IntoIterator<Item=bool>) -> Result<Packet, DecodeError> {
+        decode(bits.into_iter()).map(|(packet, _)| packet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits(s: &str) -> Vec<bool> {
+        s.chars().filter(|c| !c.is_whitespace()).map(|c| c == '1').collect()
+    }
+
+    fn error(s: &str) -> DecodeError {
+        Packet::try_parse(bits(s)).unwrap_err()
+    }
+
+    #[test]
+    fn decodes_bits() {
+        let literal = Packet::try_parse(bits("110 100 10111 11110 00101 000")).unwrap();
+        assert_eq!(literal, Packet { version: 6, body: PacketBody::Literal(2021) });
+        let two = "001 100 00001 010 100 00010";
+        let sum = Packet::try_parse(bits(&format!("000 000 0 000000000010110 {}", two))).unwrap();
+        assert_eq!(sum.to_string(), "(+ 1 2)");
+    }
+
+    #[test]
+    fn locates_errors() {
+        let truncated = error("110 100 10111 11110 0010");
+        assert_eq!(truncated, DecodeError { offset: 16, kind: DecodeErrorKind::Truncated("literal group") });
+        assert_eq!(error("110 1").kind, DecodeErrorKind::Truncated("type ID"));
+        let overrun = error("000 000 0 000000000001010 001 100 00001");
+        assert_eq!(overrun, DecodeError { offset: 28, kind: DecodeErrorKind::Overrun(32) });
+        let two = "001 100 00001 001 100 00010";
+        let nested = error(&format!("000 000 0 000000000010110 000 000 0 000000000010110 {}", two));
+        assert_eq!(nested, DecodeError { offset: 29, kind: DecodeErrorKind::Overrun(44) });
+        let arity = error("000 101 1 00000000001 001 100 00001");
+        let kind = DecodeErrorKind::Arity(ArityError { operator: OperatorKind::GreaterThan, count: 1 });
+        assert_eq!(arity, DecodeError { offset: 0, kind });
+        let too_large = format!("000 100 {} 00001", "10001 ".repeat(16));
+        assert_eq!(error(&too_large), DecodeError { offset: 86, kind: DecodeErrorKind::LiteralTooLarge });
+    }
+}
@@ -0,0 +1,170 @@
+//! Encoding packets back into hexadecimal transmissions.
+
+use std::fmt::{self, Display};
+
+use crate::{Packet, PacketBody, LITERAL_TYPE_ID};
+
+/// How an operator packet records the extent of its sub-packets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthType {
+    /// Length type 0: the total length of the sub-packets in bits.
+    Bits,
+    /// Length type 1: the number of sub-packets.
+    Count
+}
+
+const BITS_LENGTH_BITS: usize = 15;
+const COUNT_LENGTH_BITS: usize = 11;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeError {
+    /// A version that does not fit in 3 bits.
+    Version(u64),
+    /// Sub-packets too long for the 15 bit length field.
+    TooManyBits(usize),
+    /// Too many sub-packets for the 11 bit count field.
+    TooManyPackets(usize)
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Version(version) => write!(f, "version {} does not fit in 3 bits", version),
+            Self::TooManyBits(bits) => write!(
+                f,
+                "{} bits of sub-packets do not fit in a {} bit length",
+                bits,
+                BITS_LENGTH_BITS
+            ),
+            Self::TooManyPackets(count) => {
+                write!(f, "{} sub-packets do not fit in a {} bit count", count, COUNT_LENGTH_BITS)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+fn push_number(bits: &mut Vec<bool>, n: u64, len: usize) {
+    bits.extend((0..len).rev().map(|i| n >> i & 1 != 0));
+}
+
+impl Packet {
+    fn encode_bits(
+        &self,
+        bits: &mut Vec<bool>,
+        length_type: &mut impl FnMut(&Packet) -> LengthType
+    ) -> Result<(), EncodeError> {
+        if self.version >= 8 {
+            return Err(EncodeError::Version(self.version));
+        }
+        push_number(bits, self.version, 3);
+        match &self.body {
+            PacketBody::Literal(n) => {
+                push_number(bits, LITERAL_TYPE_ID, 3);
+                let groups = ((64 - n.leading_zeros() as usize).div_ceil(4)).max(1);
+                for group in (0..groups).rev() {
+                    bits.push(group != 0);
+                    push_number(bits, n >> (group * 4) & 0xF, 4);
+                }
+            }
+            PacketBody::Operator(kind, packets) => {
+                push_number(bits, kind.type_id(), 3);
+                match length_type(self) {
+                    LengthType::Bits => {
+                        bits.push(false);
+                        let mut sub_bits = Vec::new();
+                        for packet in packets {
+                            packet.encode_bits(&mut sub_bits, length_type)?;
+                        }
+                        if sub_bits.len() >= 1 << BITS_LENGTH_BITS {
+                            return Err(EncodeError::TooManyBits(sub_bits.len()));
+                        }
+                        push_number(bits, sub_bits.len() as u64, BITS_LENGTH_BITS);
+                        bits.extend(sub_bits);
+                    }
+                    LengthType::Count => {
+                        bits.push(true);
+                        if packets.len() >= 1 << COUNT_LENGTH_BITS {
+                            return Err(EncodeError::TooManyPackets(packets.len()));
+                        }
+                        push_number(bits, packets.len() as u64, COUNT_LENGTH_BITS);
+                        for packet in packets {
+                            packet.encode_bits(bits, length_type)?;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Encodes this packet as hexadecimal, padded with zero bits to a whole
+    /// number of bytes, using `length_type` for every operator packet.
+    pub fn to_hex(&self, length_type: LengthType) -> Result<String, EncodeError> {
+        self.to_hex_with(|_| length_type)
+    }
+
+    /// Like [`to_hex`](Self::to_hex), but chooses the length type for each
+    /// operator packet by calling `length_type` with it.
+    pub fn to_hex_with(
+        &self,
+        mut length_type: impl FnMut(&Packet) -> LengthType
+    ) -> Result<String, EncodeError> {
+        let mut bits = Vec::new();
+        self.encode_bits(&mut bits, &mut length_type)?;
+        bits.resize(bits.len().next_multiple_of(8), false);
+        Ok(bits
+            .chunks(4)
+            .map(|nibble| {
+                let digit = nibble.iter().fold(0, |n, &b| n << 1 | b as u32);
+                char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use crate::{Day16, OperatorKind};
+    use super::*;
+
+    fn decode(hex: &str) -> Packet {
+        Day16::parse(hex.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn encodes_examples_exactly() {
+        assert_eq!(decode("D2FE28").to_hex(LengthType::Bits).unwrap(), "D2FE28");
+        assert_eq!(decode("38006F45291200").to_hex(LengthType::Bits).unwrap(), "38006F45291200");
+        assert_eq!(decode("EE00D40C823060").to_hex(LengthType::Count).unwrap(), "EE00D40C823060");
+    }
+
+    #[test]
+    fn round_trips_with_either_length_type() {
+        let packet = decode("9C0141080250320F1802104A08");
+        for length_type in [LengthType::Bits, LengthType::Count] {
+            assert_eq!(decode(&packet.to_hex(length_type).unwrap()), packet);
+        }
+        let mut alternate = false;
+        let hex = packet.to_hex_with(|_| {
+            alternate = !alternate;
+            if alternate { LengthType::Bits } else { LengthType::Count }
+        });
+        assert_eq!(decode(&hex.unwrap()), packet);
+    }
+
+    #[test]
+    fn rejects_unencodable_packets() {
+        let literal = |n| Packet { version: 0, body: PacketBody::Literal(n) };
+        let wide = Packet {
+            version: 1,
+            body: PacketBody::Operator(OperatorKind::Sum, vec![literal(1); 3000])
+        };
+        assert_eq!(wide.to_hex(LengthType::Count), Err(EncodeError::TooManyPackets(3000)));
+        assert_eq!(wide.to_hex(LengthType::Bits), Err(EncodeError::TooManyBits(3000 * 11)));
+        let bad_version = Packet { version: 8, body: PacketBody::Literal(0) };
+        assert_eq!(bad_version.to_hex(LengthType::Bits), Err(EncodeError::Version(8)));
+    }
+}
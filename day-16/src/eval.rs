@@ -0,0 +1,206 @@
+//! Evaluating the expression a packet encodes, in any [`Number`] type.
+
+use std::fmt::{self, Display};
+
+use crate::{ArityError, BigUint, OperatorKind, Packet, PacketBody};
+
+/// An unsigned integer type that packets can be evaluated in.
+pub trait Number: Clone + Ord {
+    fn from_u64(n: u64) -> Self;
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+
+    fn wrapping_add(&self, rhs: &Self) -> Self;
+
+    fn wrapping_mul(&self, rhs: &Self) -> Self;
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*) => {$(
+        impl Number for $t {
+            fn from_u64(n: u64) -> Self {
+                n.into()
+            }
+
+            fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_add(*self, *rhs)
+            }
+
+            fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_mul(*self, *rhs)
+            }
+
+            fn wrapping_add(&self, rhs: &Self) -> Self {
+                <$t>::wrapping_add(*self, *rhs)
+            }
+
+            fn wrapping_mul(&self, rhs: &Self) -> Self {
+                <$t>::wrapping_mul(*self, *rhs)
+            }
+        }
+    )*}
+}
+
+impl_number!(u64, u128);
+
+/// Never overflows, so both kinds of arithmetic are exact.
+impl Number for BigUint {
+    fn from_u64(n: u64) -> Self {
+        n.into()
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+
+    fn wrapping_add(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+
+    fn wrapping_mul(&self, rhs: &Self) -> Self {
+        self * rhs
+    }
+}
+
+/// What evaluation does when a sum or product overflows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arithmetic {
+    /// Stop with an [`EvalErrorKind::Overflow`] error.
+    Checked,
+    /// Wrap around modulo the size of the type.
+    Wrapping
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvalErrorKind {
+    Arity(ArityError),
+    /// A sum or product too large for the type being evaluated in.
+    Overflow(OperatorKind)
+}
+
+impl Display for EvalErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Arity(e) => write!(f, "{}", e),
+            Self::Overflow(operator) => write!(f, "`{}` overflows", operator.symbol())
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvalError {
+    /// The index of each sub-packet on the way from the outermost packet to
+    /// the offending one.
+    pub path: Vec<usize>,
+    pub kind: EvalErrorKind
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{} in the outermost packet", self.kind)
+        } else {
+            let path = self.path.iter().map(ToString::to_string).collect::<Vec<_>>();
+            write!(f, "{} in sub-packet {}", self.kind, path.join("."))
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+impl Packet {
+    fn evaluate_at<T: Number>(
+        &self,
+        arithmetic: Arithmetic,
+        path: &mut Vec<usize>
+    ) -> Result<T, EvalError> {
+        let (operator, packets) = match &self.body {
+            PacketBody::Literal(n) => return Ok(T::from_u64(*n)),
+            PacketBody::Operator(operator, packets) => (*operator, packets)
+        };
+        if let Err(e) = operator.check_arity(packets.len()) {
+            return Err(EvalError { path: path.clone(), kind: EvalErrorKind::Arity(e) });
+        }
+        let mut values = Vec::<T>::with_capacity(packets.len());
+        for (i, packet) in packets.iter().enumerate() {
+            path.push(i);
+            values.push(packet.evaluate_at(arithmetic, path)?);
+            path.pop();
+        }
+        let fold = |op: fn(&T, &T) -> Option<T>, wrapping: fn(&T, &T) -> T| {
+            let mut values = values.iter();
+            let first = values.next().unwrap().clone();
+            values.try_fold(first, |acc, n| match arithmetic {
+                Arithmetic::Checked => op(&acc, n),
+                Arithmetic::Wrapping => Some(wrapping(&acc, n))
+            })
+            .ok_or_else(|| EvalError { path: path.clone(), kind: EvalErrorKind::Overflow(operator) })
+        };
+        let compare = |matches: fn(&T, &T) -> bool| T::from_u64(matches(&values[0], &values[1]) as u64);
+        Ok(match operator {
+            OperatorKind::Sum         => fold(T::checked_add, T::wrapping_add)?,
+            OperatorKind::Product     => fold(T::checked_mul, T::wrapping_mul)?,
+            OperatorKind::Min         => values.iter().min().unwrap().clone(),
+            OperatorKind::Max         => values.iter().max().unwrap().clone(),
+            OperatorKind::GreaterThan => compare(T::gt),
+            OperatorKind::LessThan    => compare(T::lt),
+            OperatorKind::EqualTo     => compare(T::eq)
+        })
+    }
+
+    /// The value of the expression this packet encodes in `T`, or an error
+    /// for the first operator that has the wrong number of sub-packets or, in
+    /// checked arithmetic, overflows.
+    pub fn evaluate_as<T: Number>(&self, arithmetic: Arithmetic) -> Result<T, EvalError> {
+        self.evaluate_at(arithmetic, &mut Vec::new())
+    }
+
+    /// The value of the expression this packet encodes in checked `u64`
+    /// arithmetic.
+    pub fn evaluate(&self) -> Result<u64, EvalError> {
+        self.evaluate_as(Arithmetic::Checked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_the_path_to_bad_operators() {
+        let packet = "(+ 1 (* 2 (< 3)))".parse::<Packet>().unwrap();
+        let error = packet.evaluate().unwrap_err();
+        let kind = EvalErrorKind::Arity(ArityError { operator: OperatorKind::LessThan, count: 1 });
+        assert_eq!(error, EvalError { path: vec![1, 1], kind });
+        let message = "`<` operator has 1 sub-packet(s), expected exactly 2 in sub-packet 1.1";
+        assert_eq!(error.to_string(), message);
+        assert_eq!("(max)".parse::<Packet>().unwrap().evaluate().unwrap_err().path, []);
+        assert_eq!("(min 4 (= 2 2))".parse::<Packet>().unwrap().evaluate(), Ok(1));
+    }
+
+    #[test]
+    fn widens_or_reports_overflow() {
+        let max = u64::MAX;
+        let packet = format!("(+ 1 (max 0 (* {} {})))", max, max).parse::<Packet>().unwrap();
+        let error = packet.evaluate().unwrap_err();
+        let kind = EvalErrorKind::Overflow(OperatorKind::Product);
+        assert_eq!(error, EvalError { path: vec![1, 1], kind });
+        assert_eq!(error.to_string(), "`*` overflows in sub-packet 1.1");
+        let wrapped = packet.evaluate_as::<u64>(Arithmetic::Wrapping);
+        assert_eq!(wrapped, Ok(max.wrapping_mul(max).wrapping_add(1)));
+        let wide = packet.evaluate_as::<u128>(Arithmetic::Checked);
+        assert_eq!(wide, Ok(max as u128 * max as u128 + 1));
+        let big = packet.evaluate_as::<BigUint>(Arithmetic::Checked).unwrap();
+        assert_eq!(big.to_string(), (max as u128 * max as u128 + 1).to_string());
+        let huge = format!("(* {} {} {})", max, max, max).parse::<Packet>().unwrap();
+        assert!(huge.evaluate_as::<u128>(Arithmetic::Checked).is_err());
+        let cube = huge.evaluate_as::<BigUint>(Arithmetic::Checked).unwrap();
+        assert_eq!(cube.to_string(), "6277101735386680762814942322444851025767571854389858533375");
+    }
+}
@@ -0,0 +1,256 @@
+//! Packets written as S-expressions, such as `(+ 1 (* 2 3) (> 5 4))`.
+//!
+//! Each packet may be prefixed with its version, as in `v6:(+ v1:1 v2:3)`;
+//! packets without one have version 0.
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::{OperatorKind, Packet, PacketBody};
+
+/// Parses a number written only in ASCII digits, unlike `u64::from_str`,
+/// which also takes a leading `+`.
+fn digits(s: &str) -> Option<u64> {
+    s.bytes().all(|b| b.is_ascii_digit()).then(|| s.parse().ok()).flatten()
+}
+
+impl OperatorKind {
+    /// The name of this operator in an expression.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Sum => "+",
+            Self::Product => "*",
+            Self::Min => "min",
+            Self::Max => "max",
+            Self::GreaterThan => ">",
+            Self::LessThan => "<",
+            Self::EqualTo => "="
+        }
+    }
+
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        Some(match symbol {
+            "+" => Self::Sum,
+            "*" => Self::Product,
+            "min" => Self::Min,
+            "max" => Self::Max,
+            ">" => Self::GreaterThan,
+            "<" => Self::LessThan,
+            "=" => Self::EqualTo,
+            _ => return None
+        })
+    }
+}
+
+impl Packet {
+    fn fmt_expr(&self, f: &mut fmt::Formatter<'_>, versions: bool) -> fmt::Result {
+        if versions {
+            write!(f, "v{}:", self.version)?;
+        }
+        match &self.body {
+            PacketBody::Literal(n) => write!(f, "{}", n),
+            PacketBody::Operator(kind, packets) => {
+                write!(f, "({}", kind.symbol())?;
+                for packet in packets {
+                    f.write_str(" ")?;
+                    packet.fmt_expr(f, versions)?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+
+    /// Displays this packet as an expression that includes every version.
+    pub fn with_versions(&self) -> WithVersions<'_> {
+        WithVersions(self)
+    }
+}
+
+/// Displays the packet tree as an expression, leaving out versions.
+impl Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_expr(f, false)
+    }
+}
+
+/// A packet displayed with its versions; see [`Packet::with_versions`].
+#[derive(Debug, Clone, Copy)]
+pub struct WithVersions<'a>(&'a Packet);
+
+impl Display for WithVersions<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_expr(f, true)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseExprError {
+    /// The byte index of the offending token, or the length of the input if
+    /// it ended too early.
+    pub index: usize,
+    pub reason: String
+}
+
+impl Display for ParseExprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at index {}", self.reason, self.index)
+    }
+}
+
+impl std::error::Error for ParseExprError {}
+
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace() || c == '(' || c == ')'
+}
+
+/// Parses an expression, one byte index at a time.
+struct Parser<'a> {
+    s: &'a str,
+    /// The byte index of the next character.
+    index: usize
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, index: usize, reason: impl Into<String>) -> ParseExprError {
+        ParseExprError { index, reason: reason.into() }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.s[self.index..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.s[self.index..];
+        self.index += rest.len() - rest.trim_start().len();
+    }
+
+    /// Reads up to the next bracket or whitespace.
+    fn atom(&mut self) -> &'a str {
+        let rest = &self.s[self.index..];
+        let len = rest.find(is_delimiter).unwrap_or(rest.len());
+        self.index += len;
+        &rest[..len]
+    }
+
+    fn packet(&mut self) -> Result<Packet, ParseExprError> {
+        self.skip_whitespace();
+        let start = self.index;
+        if self.peek() == Some('(') {
+            return self.operator(0);
+        }
+        let atom = self.atom();
+        let (version, literal) = match atom.strip_prefix('v').and_then(|a| a.split_once(':')) {
+            Some((version, literal)) => {
+                let version = digits(version)
+                    .ok_or_else(|| self.error(start + 1, format!("invalid version `{}`", version)))?;
+                (version, literal)
+            }
+            None => (0, atom)
+        };
+        // A version on its own must be followed by an operator packet.
+        if literal.is_empty() && !atom.is_empty() {
+            return match self.peek() {
+                Some('(') => self.operator(version),
+                _ => Err(self.error(self.index, "expected `(` or a number after the version"))
+            };
+        }
+        let index = self.index - literal.len();
+        match digits(literal) {
+            Some(n) => Ok(Packet { version, body: PacketBody::Literal(n) }),
+            None if literal.is_empty() => Err(self.error(index, match self.peek() {
+                Some(_) => "unexpected `)`",
+                None => "expected a packet"
+            })),
+            None => Err(self.error(index, format!("expected a number, found `{}`", literal)))
+        }
+    }
+
+    /// Parses an operator packet from its opening bracket.
+    fn operator(&mut self, version: u64) -> Result<Packet, ParseExprError> {
+        let open = self.index;
+        self.index += 1;
+        self.skip_whitespace();
+        let index = self.index;
+        let symbol = self.atom();
+        let kind = OperatorKind::from_symbol(symbol).ok_or_else(|| match symbol {
+            "" => self.error(index, "expected an operator"),
+            _ => self.error(index, format!("unknown operator `{}`", symbol))
+        })?;
+        let mut packets = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some(')') => break,
+                Some(_) => packets.push(self.packet()?),
+                None => return Err(self.error(self.index, format!("unclosed `(` at index {}", open)))
+            }
+        }
+        self.index += 1;
+        Ok(Packet { version, body: PacketBody::Operator(kind, packets) })
+    }
+}
+
+/// Parses an expression in the form written by [`Display`], with or without
+/// versions.
+impl FromStr for Packet {
+    type Err = ParseExprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { s, index: 0 };
+        let packet = parser.packet()?;
+        parser.skip_whitespace();
+        match parser.peek() {
+            None => Ok(packet),
+            Some(')') => Err(parser.error(parser.index, "unexpected `)`")),
+            Some(_) => Err(parser.error(parser.index, "expected the end of the expression"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use crate::Day16;
+    use super::*;
+
+    fn decode(hex: &str) -> Packet {
+        Day16::parse(hex.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn displays_expressions() {
+        assert_eq!(decode("9C0141080250320F1802104A08").to_string(), "(= (+ 1 3) (* 2 2))");
+        assert_eq!(decode("C200B40A82").with_versions().to_string(), "v6:(+ v6:1 v2:2)");
+        assert_eq!(decode("D2FE28").to_string(), "2021");
+    }
+
+    #[test]
+    fn parses_what_it_displays() {
+        let expr = "(+ 1 (* 2 3) (> 5 4))";
+        let packet = expr.parse::<Packet>().unwrap();
+        assert_eq!(packet.to_string(), expr);
+        assert_eq!(packet.evaluate(), Ok(8));
+        for hex in ["8A004A801A8002F478", "A0016C880162017C3686B18A3D4780", "9C0141080250320F1802104A08"] {
+            let packet = decode(hex);
+            assert_eq!(packet.with_versions().to_string().parse(), Ok(packet));
+        }
+        let packet = " ( min\n7  v3:9 v1:(max 2) ) ".parse::<Packet>().unwrap();
+        assert_eq!(packet.with_versions().to_string(), "v0:(min v0:7 v3:9 v1:(max v0:2))");
+    }
+
+    #[test]
+    fn reports_error_indices() {
+        let error = |s: &str| s.parse::<Packet>().map(|_| ()).unwrap_err();
+        let unclosed = ParseExprError { index: 6, reason: "unclosed `(` at index 0".to_string() };
+        assert_eq!(error("(+ 1 2"), unclosed);
+        assert_eq!(error("(- 1 2)").index, 1);
+        assert_eq!(error("(+ 1 x)").index, 5);
+        assert_eq!(error("(+ 1) 2").index, 6);
+        assert_eq!(error("vx:1").index, 1);
+        assert_eq!(error("(+ 1 +5)").reason, "expected a number, found `+5`");
+        assert_eq!(error("v+1:5").reason, "invalid version `+1`");
+        assert_eq!(error(")").reason, "unexpected `)`");
+        assert_eq!(error("").reason, "expected a packet");
+        assert_eq!(error("()").reason, "expected an operator");
+    }
+}
@@ -0,0 +1,196 @@
+use std::io::BufRead;
+use std::fmt::{self, Display};
+use aoc::ParseError;
+
+mod bigint;
+mod decode;
+mod encode;
+mod eval;
+mod expr;
+mod simplify;
+mod stream;
+
+pub use decode::{DecodeError, DecodeErrorKind};
+pub use bigint::BigUint;
+pub use encode::{LengthType, EncodeError};
+pub use eval::{Arithmetic, EvalError, EvalErrorKind, Number};
+pub use simplify::Simplified;
+pub use stream::{BitReader, Transmission, Transmissions};
+pub use expr::{ParseExprError, WithVersions};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub version: u64,
+    pub body: PacketBody
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketBody {
+    Literal(u64),
+    Operator(OperatorKind, Vec<Packet>)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperatorKind {
+    Sum,
+    Product,
+    Min,
+    Max,
+    GreaterThan,
+    LessThan,
+    EqualTo
+}
+
+impl OperatorKind {
+    pub fn type_id(self) -> u64 {
+        match self {
+            Self::Sum => 0,
+            Self::Product => 1,
+            Self::Min => 2,
+            Self::Max => 3,
+            Self::GreaterThan => 5,
+            Self::LessThan => 6,
+            Self::EqualTo => 7
+        }
+    }
+
+    pub fn from_type_id(id: u64) -> Option<Self> {
+        Some(match id {
+            0 => Self::Sum,
+            1 => Self::Product,
+            2 => Self::Min,
+            3 => Self::Max,
+            5 => Self::GreaterThan,
+            6 => Self::LessThan,
+            7 => Self::EqualTo,
+            _ => return None
+        })
+    }
+
+    /// Whether this operator takes `count` sub-packets: exactly two for the
+    /// comparisons and at least one for the others.
+    pub fn accepts(self, count: usize) -> bool {
+        match self {
+            Self::GreaterThan | Self::LessThan | Self::EqualTo => count == 2,
+            _ => count >= 1
+        }
+    }
+
+    /// The number of sub-packets [`accepts`](Self::accepts) allows, in words.
+    pub fn arity(self) -> &'static str {
+        match self {
+            Self::GreaterThan | Self::LessThan | Self::EqualTo => "exactly 2",
+            _ => "at least 1"
+        }
+    }
+
+    /// Like [`accepts`](Self::accepts), but with an error for a bad count.
+    pub fn check_arity(self, count: usize) -> Result<(), ArityError> {
+        if self.accepts(count) {
+            Ok(())
+        } else {
+            Err(ArityError { operator: self, count })
+        }
+    }
+}
+
+/// An operator with a number of sub-packets it does not accept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArityError {
+    pub operator: OperatorKind,
+    pub count: usize
+}
+
+impl Display for ArityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` operator has {} sub-packet(s), expected {}",
+            self.operator.symbol(),
+            self.count,
+            self.operator.arity()
+        )
+    }
+}
+
+impl std::error::Error for ArityError {}
+
+/// The type ID of literal packets.
+const LITERAL_TYPE_ID: u64 = 4;
+
+impl Packet {
+    fn version_sum(&self) -> u64 {
+        let mut sum = self.version;
+        if let PacketBody::Operator(_, packets) = &self.body {
+            sum += packets.iter().map(Self::version_sum).sum::<u64>();
+        }
+        sum
+    }
+
+    /// The number of packets in this one, itself included.
+    fn size(&self) -> usize {
+        match &self.body {
+            PacketBody::Literal(_) => 1,
+            PacketBody::Operator(_, packets) => 1 + packets.iter().map(Self::size).sum::<usize>()
+        }
+    }
+}
+
+pub struct Day16;
+
+impl aoc::Solution for Day16 {
+    type Input = Packet;
+    type Answer1 = u64;
+    type Answer2 = BigUint;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        match BitReader::new(input).next_transmission() {
+            Some(transmission) => Ok(transmission?.packet),
+            None => Err(ParseError::new(1, 1, "expected a hexadecimal transmission, found end of input"))
+        }
+    }
+
+    fn part_1(input: &Self::Input) -> u64 {
+        input.version_sum()
+    }
+
+    fn part_2(input: &Self::Input) -> BigUint {
+        input
+            .evaluate_as(Arithmetic::Checked)
+            .expect("parsing checks the number of sub-packets")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    fn version_sum(hex: &str) -> u64 {
+        Day16::part_1(&Day16::parse(hex.as_bytes()).unwrap())
+    }
+
+    fn evaluate(hex: &str) -> u64 {
+        Day16::parse(hex.as_bytes()).unwrap().evaluate().unwrap()
+    }
+
+    #[test]
+    fn version_sum_examples() {
+        assert_eq!(version_sum("8A004A801A8002F478"), 16);
+        assert_eq!(version_sum("620080001611562C8802118E34"), 12);
+        assert_eq!(version_sum("C0015000016115A2E0802F182340"), 23);
+        assert_eq!(version_sum("A0016C880162017C3686B18A3D4780"), 31);
+    }
+
+    #[test]
+    fn evaluate_examples() {
+        assert_eq!(evaluate("C200B40A82"), 3);
+        assert_eq!(evaluate("04005AC33890"), 54);
+        assert_eq!(evaluate("880086C3E88112"), 7);
+        assert_eq!(evaluate("CE00C43D881120"), 9);
+        assert_eq!(evaluate("D8005AC2A8F0"), 1);
+        assert_eq!(evaluate("F600BC2D8F"), 0);
+        assert_eq!(evaluate("9C005AC2F8F0"), 0);
+        assert_eq!(evaluate("9C0141080250320F1802104A08"), 1);
+    }
+}
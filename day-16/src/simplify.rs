@@ -0,0 +1,121 @@
+//! Shrinking packet trees without changing what they evaluate to.
+
+use crate::{OperatorKind, Packet, PacketBody};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Simplified {
+    pub packet: Packet,
+    /// How many fewer packets `packet` has than the original.
+    pub removed: usize
+}
+
+impl Packet {
+    fn simplified(&self) -> Packet {
+        let PacketBody::Operator(operator, packets) = &self.body else {
+            return self.clone();
+        };
+        let operator = *operator;
+        let simplified = packets.iter().map(Packet::simplified).collect::<Vec<_>>();
+        if simplified.iter().all(|p| matches!(p.body, PacketBody::Literal(_))) {
+            // Folding only in checked u64 keeps the value the same in every
+            // number type, and leaves bad operators to fail at evaluation.
+            let body = PacketBody::Operator(operator, simplified.clone());
+            if let Ok(n) = (Packet { version: self.version, body }).evaluate() {
+                return Packet { version: self.version, body: PacketBody::Literal(n) };
+            }
+        }
+        // Only sums merge: a partial sum never exceeds the whole one, but
+        // multiplying in another order can overflow where the original did
+        // not, or hide an overflow behind a zero factor.
+        let mut children = Vec::with_capacity(simplified.len());
+        for packet in simplified {
+            match packet.body {
+                PacketBody::Operator(OperatorKind::Sum, grandchildren)
+                    if operator == OperatorKind::Sum
+                        && OperatorKind::Sum.accepts(grandchildren.len()) =>
+                {
+                    children.extend(grandchildren);
+                }
+                body => children.push(Packet { version: packet.version, body })
+            }
+        }
+        if children.len() == 1 && matches!(operator, OperatorKind::Min | OperatorKind::Max) {
+            return children.pop().unwrap();
+        }
+        Packet { version: self.version, body: PacketBody::Operator(operator, children) }
+    }
+
+    /// An equivalent packet with operators on literals replaced by their
+    /// value, sums of sums merged, and minimums and maximums of one packet
+    /// replaced by that packet.
+    ///
+    /// In every number type, the simplified packet evaluates without error
+    /// exactly when this one does, to the same value. The versions of
+    /// removed packets are lost.
+    pub fn simplify(&self) -> Simplified {
+        let packet = self.simplified();
+        let removed = self.size() - packet.size();
+        Simplified { packet, removed }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use crate::{Arithmetic, Day16};
+    use super::*;
+
+    fn simplify(expr: &str) -> (String, usize) {
+        let simplified = expr.parse::<Packet>().unwrap().simplify();
+        (simplified.packet.to_string(), simplified.removed)
+    }
+
+    #[test]
+    fn folds_flattens_and_unwraps() {
+        assert_eq!(simplify("(+ 1 (* 2 3) (> 5 4))"), ("8".to_string(), 7));
+        assert_eq!(simplify("(+ (+ 1 (+ 2 (* 3 (* 4 (max (min 5 6)))))) 7)").0, "70");
+        // Products that overflow u64 cannot fold, but can still move.
+        let wide = format!("(+ 1 (+ 2 (* {0} 2)) (max (* {0} 3)))", u64::MAX);
+        let (simplified, removed) = simplify(&wide);
+        assert_eq!(simplified, format!("(+ 1 2 (* {0} 2) (* {0} 3))", u64::MAX));
+        assert_eq!(removed, 2);
+        let value = |expr: &str| {
+            expr.parse::<Packet>().unwrap().evaluate_as::<u128>(Arithmetic::Checked)
+        };
+        assert_eq!(value(&simplified), value(&wide));
+        assert_eq!(simplify("(= 1 1)"), ("1".to_string(), 2));
+        assert_eq!(simplify("7"), ("7".to_string(), 0));
+    }
+
+    #[test]
+    fn keeps_packets_that_cannot_fold() {
+        let overflow = format!("(* {0} (* 2 {0}))", u64::MAX);
+        assert_eq!(simplify(&overflow), (overflow.clone(), 0));
+        assert_eq!(simplify("(max (+) (< 1))"), ("(max (+) (< 1))".to_string(), 0));
+        assert_eq!(simplify("(+ 1 (+))"), ("(+ 1 (+))".to_string(), 0));
+    }
+
+    #[test]
+    fn keeps_products_in_order() {
+        let max = u64::MAX;
+        let hidden = format!("(* (* {0} {0}) (* 2 (< (* {0} {0}) 1)))", max);
+        let packet = hidden.parse::<Packet>().unwrap();
+        let simplified = packet.simplify().packet;
+        assert_eq!(simplified, packet);
+        assert_eq!(simplified.evaluate_as::<u128>(Arithmetic::Checked), Ok(0));
+        let overflow = format!("(* 0 (* {} 2))", max).parse::<Packet>().unwrap();
+        let simplified = overflow.simplify().packet;
+        assert_eq!(simplified, overflow);
+        assert!(simplified.evaluate().is_err());
+    }
+
+    #[test]
+    fn preserves_example_values() {
+        for hex in ["C200B40A82", "04005AC33890", "880086C3E88112", "9C0141080250320F1802104A08"] {
+            let packet = Day16::parse(hex.as_bytes()).unwrap();
+            let simplified = packet.simplify();
+            assert_eq!(simplified.packet.evaluate(), packet.evaluate());
+            assert_eq!(simplified.removed, packet.size() - 1);
+        }
+    }
+}
@@ -0,0 +1,263 @@
+//! Reading transmissions straight from hexadecimal input, one per line.
+
+use std::io::{BufRead, BufReader, ErrorKind, Read};
+
+use aoc::ParseError;
+
+use crate::decode::decode;
+use crate::{Packet, PacketBody};
+
+/// The bits of hexadecimal input, read one byte at a time. As an iterator it
+/// yields the bits of the current line, stopping at its end.
+pub struct BitReader<R> {
+    input: R,
+    /// The hexadecimal digit being read.
+    digit: u8,
+    /// How many bits of `digit` have not been read yet.
+    bits_left: u8,
+    /// The number of the current line, or 0 before the first.
+    line: usize,
+    /// How many digits of the current line have been read.
+    digits: usize,
+    /// Whether the newline at the end of the current line has been read.
+    line_ended: bool,
+    /// Whether reading failed, after which the input counts as empty.
+    input_failed: bool,
+    /// The first error on the current line, which also stops its bits.
+    error: Option<ParseError>
+}
+
+impl<R: Read> BitReader<BufReader<R>> {
+    pub fn from_read(input: R) -> Self {
+        Self::new(BufReader::new(input))
+    }
+}
+
+impl<R: BufRead> BitReader<R> {
+    pub fn new(input: R) -> Self {
+        BitReader {
+            input,
+            digit: 0,
+            bits_left: 0,
+            line: 0,
+            digits: 0,
+            line_ended: true,
+            input_failed: false,
+            error: None
+        }
+    }
+
+    fn peek_byte(&mut self) -> Option<u8> {
+        while !self.input_failed {
+            match self.input.fill_buf() {
+                Ok(buf) => return buf.first().copied(),
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    self.fail(e.to_string());
+                    self.input_failed = true;
+                }
+            }
+        }
+        None
+    }
+
+    fn next_byte(&mut self) -> Option<u8> {
+        let byte = self.peek_byte()?;
+        self.input.consume(1);
+        Some(byte)
+    }
+
+    fn fail(&mut self, reason: String) {
+        self.fail_at(self.digits + 1, reason);
+    }
+
+    fn fail_at(&mut self, column: usize, reason: impl Into<String>) {
+        self.error.get_or_insert(ParseError::new(self.line, column, reason));
+    }
+
+    /// Skips the rest of the current line and any blank lines after it, and
+    /// returns whether there is another line to read.
+    pub fn next_line(&mut self) -> Result<bool, ParseError> {
+        while !self.line_ended {
+            match self.next_byte() {
+                Some(b'\n') | None => self.line_ended = true,
+                Some(_) => {}
+            }
+        }
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+        loop {
+            match self.peek_byte() {
+                Some(b'\r' | b'\n') => {
+                    self.line += (self.next_byte() == Some(b'\n')) as usize;
+                }
+                Some(_) => break,
+                None => return self.error.take().map_or(Ok(false), Err)
+            }
+        }
+        self.line += 1;
+        self.digits = 0;
+        self.bits_left = 0;
+        self.line_ended = false;
+        Ok(true)
+    }
+
+    /// Decodes the packet on the next line that has one, or returns `None` at
+    /// the end of the input.
+    pub fn next_transmission(&mut self) -> Option<Result<Transmission, ParseError>> {
+        match self.next_line() {
+            Ok(true) => {}
+            Ok(false) => return None,
+            Err(e) => return Some(Err(e))
+        }
+        let line = self.line;
+        let decoded = decode(&mut *self);
+        // Only zero bits may pad the packet out to the end of the line.
+        if decoded.is_ok() && self.by_ref().any(|bit| bit) {
+            self.fail_at(self.digits, "expected only zero padding after the packet");
+        }
+        if let Some(error) = self.error.take() {
+            return Some(Err(error));
+        }
+        Some(match decoded {
+            Ok((packet, offsets)) => Ok(Transmission { line, packet, offsets }),
+            Err(e) => Err(ParseError::new(line, e.offset / 4 + 1, e.to_string()))
+        })
+    }
+
+    /// An iterator over the packet on every line.
+    pub fn transmissions(self) -> Transmissions<R> {
+        Transmissions(self)
+    }
+}
+
+impl<R: BufRead> Iterator for BitReader<R> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.bits_left == 0 {
+            if self.line_ended || self.error.is_some() {
+                return None;
+            }
+            match self.next_byte() {
+                Some(b'\n') | None => {
+                    self.line_ended = true;
+                    return None;
+                }
+                Some(b'\r') if self.peek_byte() == Some(b'\n') => {
+                    self.input.consume(1);
+                    self.line_ended = true;
+                    return None;
+                }
+                Some(byte) => match (byte as char).to_digit(16) {
+                    Some(digit) => {
+                        self.digit = digit as u8;
+                        self.bits_left = 4;
+                        self.digits += 1;
+                    }
+                    None => {
+                        self.fail("expected a hexadecimal digit".to_string());
+                        return None;
+                    }
+                }
+            }
+        }
+        self.bits_left -= 1;
+        Some(self.digit >> self.bits_left & 1 != 0)
+    }
+}
+
+/// An iterator over the transmissions in some input; see
+/// [`BitReader::transmissions`].
+pub struct Transmissions<R>(BitReader<R>);
+
+impl<R: BufRead> Iterator for Transmissions<R> {
+    type Item = Result<Transmission, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_transmission()
+    }
+}
+
+/// The packet decoded from one line of input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transmission {
+    pub line: usize,
+    pub packet: Packet,
+    /// The bit offset of every packet in the transmission, in the order they
+    /// were sent; see [`offset`](Self::offset).
+    pub offsets: Vec<usize>
+}
+
+impl Transmission {
+    /// The bit offset of the packet reached by taking the sub-packet at each
+    /// index of `path` in turn, starting from the outermost packet.
+    pub fn offset(&self, path: &[usize]) -> Option<usize> {
+        let mut packet = &self.packet;
+        let mut index = 0;
+        for &i in path {
+            let PacketBody::Operator(_, packets) = &packet.body else {
+                return None;
+            };
+            let child = packets.get(i)?;
+            index += 1 + packets[..i].iter().map(Packet::size).sum::<usize>();
+            packet = child;
+        }
+        self.offsets.get(index).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_lines_one_bit_at_a_time() {
+        let mut reader = BitReader::new("D2FE28\r\nA".as_bytes());
+        assert_eq!(reader.next_line(), Ok(true));
+        let bits = reader.by_ref().map(|b| if b { '1' } else { '0' }).collect::<String>();
+        assert_eq!(bits, "110100101111111000101000");
+        assert_eq!(reader.next_line(), Ok(true));
+        assert_eq!(reader.by_ref().count(), 4);
+        assert_eq!(reader.next_line(), Ok(false));
+    }
+
+    #[test]
+    fn decodes_every_line() {
+        let input = "D2FE28\n\n38006F45291200\r\nEE00D40C823060\n";
+        let transmissions = BitReader::new(input.as_bytes())
+            .transmissions()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let lines = transmissions.iter().map(|t| t.line).collect::<Vec<_>>();
+        assert_eq!(lines, [1, 3, 4]);
+        assert_eq!(transmissions[1].packet.to_string(), "(< 10 20)");
+        assert_eq!(transmissions[1].offsets, [0, 22, 33]);
+        assert_eq!(transmissions[2].offsets, [0, 18, 29, 40]);
+        assert_eq!(transmissions[2].offset(&[2]), Some(40));
+        assert_eq!(transmissions[2].offset(&[3]), None);
+        assert_eq!(transmissions[2].offset(&[0, 0]), None);
+    }
+
+    #[test]
+    fn reports_lines_and_columns() {
+        let mut transmissions = BitReader::new("D2FE28\nD2FX28\nD2FE\n8A0".as_bytes()).transmissions();
+        assert!(transmissions.next().unwrap().is_ok());
+        let error = transmissions.next().unwrap().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        let error = transmissions.next().unwrap().unwrap_err();
+        assert_eq!((error.line, error.column), (3, 5));
+        assert_eq!(transmissions.next().unwrap().unwrap_err().line, 4);
+        assert!(transmissions.next().is_none());
+        let trailing = [("D2FE28ZZZZ", 7), ("D2FE28FFFF", 7), ("D2FE28 hi", 7), ("D2FE2800F", 9)];
+        for (input, column) in trailing {
+            let error = BitReader::new(input.as_bytes()).next_transmission().unwrap().unwrap_err();
+            assert_eq!((error.line, error.column), (1, column), "{}", input);
+        }
+        let padded = BitReader::new("D2FE2800\n".as_bytes()).next_transmission().unwrap();
+        assert_eq!(padded.unwrap().packet.to_string(), "2021");
+        let first_digit = BitReader::new("EE00D40C823061".as_bytes()).next_transmission().unwrap();
+        assert_eq!(first_digit.unwrap_err().column, 14);
+    }
+}
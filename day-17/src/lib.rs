@@ -0,0 +1,107 @@
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+use std::cmp::Ordering;
+use aoc::ParseError;
+
+type TargetAxis = RangeInclusive<i32>;
+
+fn parse_target(input: impl BufRead) -> Result<(TargetAxis, TargetAxis), ParseError> {
+    fn parse_target_axis(line: &aoc::Line, s: &str, axis: &str) -> Result<TargetAxis, ParseError> {
+        let range = line.strip_prefix(s, axis)?;
+        let (from, to) = line.split_once(range, "..")?;
+        Ok(line.parse(from)?..=line.parse(to)?)
+    }
+    let line = aoc::lines(input).expect("a target area")?;
+    let target = line.strip_prefix(&line.text, "target area: ")?;
+    let (x, y) = line.split_once(target, ", ")?;
+    let tx = parse_target_axis(&line, x, "x=")?;
+    let ty = parse_target_axis(&line, y, "y=")?;
+    if *tx.start() <= 0 {
+        return Err(line.error_at(x, "the target area must be in front of the probe"));
+    }
+    if *ty.end() >= 0 {
+        return Err(line.error_at(y, "the target area must be below the probe"));
+    }
+    Ok((tx, ty))
+}
+
+fn final_x(vx: i32) -> i32 {
+    vx * (vx + 1) / 2
+}
+
+fn max_height(mut vx: i32, mut vy: i32, tx: &TargetAxis, ty: &TargetAxis) -> Option<i32> {
+    let mut x = 0;
+    let mut y = 0;
+    let mut max_y = y;
+    loop {
+        if tx.contains(&x) && ty.contains(&y) {
+            return Some(max_y);
+        }
+        if y < *ty.start() || x > *tx.end() {
+            return None;
+        }
+        x += vx;
+        y += vy;
+        match vx.cmp(&0) {
+            Ordering::Greater => vx -= 1,
+            Ordering::Less    => vx += 1,
+            _ => {}
+        }
+        vy -= 1;
+        max_y = max_y.max(y);
+    }
+}
+
+pub struct Day17;
+
+impl aoc::Solution for Day17 {
+    type Input = (TargetAxis, TargetAxis);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_target(input)
+    }
+
+    fn part_1(input: &Self::Input) -> i32 {
+        let (tx, ty) = input;
+        let vx = (0..).find(|&vx| tx.contains(&final_x(vx))).unwrap();
+        let vy_lbound = ty.start().abs();
+        (-vy_lbound..=vy_lbound)
+            .rev()
+            .flat_map(|vy| max_height(vx, vy, tx, ty))
+            .next()
+            .unwrap()
+    }
+
+    fn part_2(input: &Self::Input) -> i32 {
+        let (tx, ty) = input;
+        let mut valid_velocities = 0;
+        let vy_lbound = ty.start().abs();
+        for vx in 0..=*tx.end() {
+            for vy in -vy_lbound..=vy_lbound {
+                if max_height(vx, vy, tx, ty).is_some() {
+                    valid_velocities += 1;
+                }
+            }
+        }
+        valid_velocities
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+target area: x=20..30, y=-10..-5
+";
+
+    #[test]
+    fn example() {
+        let input = Day17::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day17::part_1(&input), 45);
+        assert_eq!(Day17::part_2(&input), 112);
+    }
+}
@@ -0,0 +1,539 @@
+use std::io::BufRead;
+use std::fmt::{self, Display};
+use std::ops::Add;
+use std::iter::{Peekable, Sum};
+use std::str::{Chars, FromStr};
+use aoc::ParseError;
+
+/// The deepest a pair can be nested before it explodes.
+const MAX_DEPTH: u8 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Leaf {
+    value: u32,
+    /// How many pairs this number is nested in.
+    depth: u8
+}
+
+/// A snailfish number, stored as its regular numbers from left to right, each
+/// tagged with its depth. The pairs are implied: two neighbouring numbers at
+/// the same depth that are not already paired with a neighbour form a pair.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SfNum {
+    leaves: Vec<Leaf>
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseSfNumErrorKind {
+    /// The input ended inside the pair opened at this index.
+    UnclosedBracket(usize),
+    /// A `]` with no `[` to close.
+    UnmatchedBracket,
+    MissingComma,
+    /// Something other than `]` after the second element of a pair.
+    ExpectedClosingBracket,
+    /// Neither a pair nor a digit where an element should be.
+    ExpectedElement,
+    /// A regular number where the whole number should be a pair.
+    ExpectedPair,
+    TrailingInput,
+    /// A pair nested inside four others, which should have exploded.
+    TooDeep,
+    /// A regular number of 10 or more, which should have split.
+    MultiDigit,
+    /// A regular number too large for 32 bits.
+    TooLarge
+}
+
+impl Display for ParseSfNumErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnclosedBracket(open) => write!(f, "unclosed `[` at index {}", open),
+            Self::UnmatchedBracket => write!(f, "unmatched `]`"),
+            Self::MissingComma => write!(f, "expected `,`"),
+            Self::ExpectedClosingBracket => write!(f, "expected `]`"),
+            Self::ExpectedElement => write!(f, "expected `[` or a digit"),
+            Self::ExpectedPair => write!(f, "expected a pair"),
+            Self::TrailingInput => write!(f, "unexpected text after the number"),
+            Self::TooDeep => write!(f, "pair is nested inside more than {} pairs", MAX_DEPTH),
+            Self::MultiDigit => write!(f, "regular numbers must be a single digit"),
+            Self::TooLarge => write!(f, "regular number does not fit in 32 bits")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseSfNumError {
+    /// The index of the offending character.
+    pub index: usize,
+    pub kind: ParseSfNumErrorKind
+}
+
+impl Display for ParseSfNumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at index {}", self.kind, self.index)
+    }
+}
+
+impl std::error::Error for ParseSfNumError {}
+
+/// Parses a snailfish number, one character per index.
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    /// The character index of the next character.
+    index: usize,
+    leaves: Vec<Leaf>,
+    /// Whether to reject numbers that could still explode or split.
+    reduced: bool
+}
+
+impl Parser<'_> {
+    fn error(&self, kind: ParseSfNumErrorKind) -> ParseSfNumError {
+        ParseSfNumError { index: self.index, kind }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn bump(&mut self) {
+        self.chars.next();
+        self.index += 1;
+    }
+
+    /// Consumes `c`, or fails with `kind`, or as unclosed if the input ended.
+    fn expect(
+        &mut self,
+        c: char,
+        open: usize,
+        kind: ParseSfNumErrorKind
+    ) -> Result<(), ParseSfNumError> {
+        match self.peek() {
+            Some(next) if next == c => {
+                self.bump();
+                Ok(())
+            }
+            Some(_) => Err(self.error(kind)),
+            None => Err(self.error(ParseSfNumErrorKind::UnclosedBracket(open)))
+        }
+    }
+
+    fn element(&mut self, depth: u8) -> Result<(), ParseSfNumError> {
+        match self.peek() {
+            // Adding two reduced numbers nests their deepest pairs one further.
+            Some('[') if depth >= MAX_DEPTH + !self.reduced as u8 => {
+                Err(self.error(ParseSfNumErrorKind::TooDeep))
+            }
+            Some('[') => {
+                let open = self.index;
+                self.bump();
+                self.element(depth + 1)?;
+                self.expect(',', open, ParseSfNumErrorKind::MissingComma)?;
+                self.element(depth + 1)?;
+                self.expect(']', open, ParseSfNumErrorKind::ExpectedClosingBracket)
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let start = self.index;
+                let error = |kind| ParseSfNumError { index: start, kind };
+                let mut value = 0u32;
+                while let Some(digit) = self.peek().and_then(|c| c.to_digit(10)) {
+                    if self.index > start && self.reduced {
+                        return Err(error(ParseSfNumErrorKind::MultiDigit));
+                    }
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|value| value.checked_add(digit))
+                        .ok_or(error(ParseSfNumErrorKind::TooLarge))?;
+                    self.bump();
+                }
+                self.leaves.push(Leaf { value, depth });
+                Ok(())
+            }
+            Some(']') => Err(self.error(ParseSfNumErrorKind::UnmatchedBracket)),
+            _ => Err(self.error(ParseSfNumErrorKind::ExpectedElement))
+        }
+    }
+}
+
+impl FromStr for SfNum {
+    type Err = ParseSfNumError;
+
+    /// Parses a reduced number, rejecting any pair that would explode and
+    /// any regular number that would split; see [`SfNum::parse_unreduced`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SfNum::parse(s, true)
+    }
+}
+
+impl SfNum {
+    /// Parses a number that may not be reduced yet, such as the sum of two
+    /// reduced numbers or a step of its [`reduction`](Self::reduction), so
+    /// that everything this type displays can be read back.
+    pub fn parse_unreduced(s: &str) -> Result<Self, ParseSfNumError> {
+        SfNum::parse(s, false)
+    }
+
+    fn parse(s: &str, reduced: bool) -> Result<Self, ParseSfNumError> {
+        let mut parser = Parser {
+            chars: s.chars().peekable(),
+            index: 0,
+            leaves: Vec::new(),
+            reduced
+        };
+        if parser.peek().is_some_and(|c| c.is_ascii_digit()) {
+            return Err(parser.error(ParseSfNumErrorKind::ExpectedPair));
+        }
+        parser.element(0)?;
+        match parser.peek() {
+            None => Ok(Self { leaves: parser.leaves }),
+            Some(']') => Err(parser.error(ParseSfNumErrorKind::UnmatchedBracket)),
+            Some(_) => Err(parser.error(ParseSfNumErrorKind::TrailingInput))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right
+}
+
+/// A single reduction step, with the path from the root to the pair that
+/// exploded or the number that split.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    Explode(Vec<Side>),
+    Split(Vec<Side>)
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (action, path) = match self {
+            Self::Explode(path) => ("explode", path),
+            Self::Split(path) => ("split", path)
+        };
+        write!(f, "{} at ", action)?;
+        if path.is_empty() {
+            return write!(f, "root");
+        }
+        for side in path {
+            write!(f, "{}", if *side == Side::Left { 'L' } else { 'R' })?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub action: Action,
+    /// The number after the action.
+    pub number: SfNum
+}
+
+/// The steps taken to reduce a number, from [`SfNum::reduction`].
+pub struct Reduction {
+    number: SfNum
+}
+
+impl Iterator for Reduction {
+    type Item = Step;
+
+    fn next(&mut self) -> Option<Step> {
+        let number = &mut self.number;
+        let action = if let Some(i) = number.next_explode() {
+            let mut path = number.leaf_path(i);
+            path.pop();
+            number.explode_at(i);
+            Action::Explode(path)
+        } else {
+            let i = number.next_split()?;
+            let path = number.leaf_path(i);
+            number.split_at(i);
+            Action::Split(path)
+        };
+        Some(Step { action, number: number.clone() })
+    }
+}
+
+impl SfNum {
+    /// The pair `[left,right]`, not yet reduced.
+    pub fn pair(left: &SfNum, right: &SfNum) -> SfNum {
+        let leaves = left.leaves
+            .iter()
+            .chain(&right.leaves)
+            .map(|&Leaf { value, depth }| Leaf { value, depth: depth + 1 })
+            .collect();
+        SfNum { leaves }
+    }
+
+    /// Reduces this number one action at a time, yielding each step.
+    pub fn reduction(self) -> Reduction {
+        Reduction { number: self }
+    }
+
+    /// The path from the root to the number at `index` in `leaves`.
+    fn leaf_path(&self, index: usize) -> Vec<Side> {
+        let mut path = Vec::new();
+        let mut depth = 0;
+        for leaf in &self.leaves[..=index] {
+            // Leaving a number: climb out of every pair it closes, then step
+            // into the right side of the pair it is the left side of.
+            while path.last() == Some(&Side::Right) {
+                path.pop();
+                depth -= 1;
+            }
+            if let Some(side) = path.last_mut() {
+                *side = Side::Right;
+            }
+            // Entering the next number: descend along left sides to its depth.
+            while depth < leaf.depth {
+                path.push(Side::Left);
+                depth += 1;
+            }
+        }
+        path
+    }
+
+    /// The index of the left number in the leftmost pair nested too deeply.
+    fn next_explode(&self) -> Option<usize> {
+        self.leaves.iter().position(|leaf| leaf.depth > MAX_DEPTH)
+    }
+
+    /// The index of the leftmost number of 10 or more.
+    fn next_split(&self) -> Option<usize> {
+        self.leaves.iter().position(|leaf| leaf.value >= 10)
+    }
+
+    fn explode_at(&mut self, i: usize) {
+        let (left, right) = (self.leaves[i], self.leaves[i + 1]);
+        if i > 0 {
+            self.leaves[i - 1].value += left.value;
+        }
+        if let Some(next) = self.leaves.get_mut(i + 2) {
+            next.value += right.value;
+        }
+        self.leaves[i] = Leaf { value: 0, depth: left.depth - 1 };
+        self.leaves.remove(i + 1);
+    }
+
+    fn split_at(&mut self, i: usize) {
+        let Leaf { value, depth } = self.leaves[i];
+        self.leaves[i] = Leaf { value: value / 2, depth: depth + 1 };
+        self.leaves.insert(i + 1, Leaf { value: value - value / 2, depth: depth + 1 });
+    }
+
+    fn reduce(&mut self) {
+        loop {
+            if let Some(i) = self.next_explode() {
+                self.explode_at(i);
+            } else if let Some(i) = self.next_split() {
+                self.split_at(i);
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub fn magnitude(&self) -> u32 {
+        let mut stack: Vec<Leaf> = Vec::new();
+        for &leaf in &self.leaves {
+            let mut leaf = leaf;
+            while let Some(&left) = stack.last() {
+                if left.depth != leaf.depth || leaf.depth == 0 {
+                    break;
+                }
+                stack.pop();
+                leaf = Leaf { value: 3 * left.value + 2 * leaf.value, depth: leaf.depth - 1 };
+            }
+            stack.push(leaf);
+        }
+        stack.first().map_or(0, |leaf| leaf.value)
+    }
+
+    fn fmt_element(&self, f: &mut fmt::Formatter<'_>, i: &mut usize, depth: u8) -> fmt::Result {
+        let leaf = self.leaves[*i];
+        if leaf.depth == depth {
+            *i += 1;
+            return write!(f, "{}", leaf.value);
+        }
+        write!(f, "[")?;
+        self.fmt_element(f, i, depth + 1)?;
+        write!(f, ",")?;
+        self.fmt_element(f, i, depth + 1)?;
+        write!(f, "]")
+    }
+}
+
+impl Display for SfNum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_element(f, &mut 0, 0)
+    }
+}
+
+impl Add for &SfNum {
+    type Output = SfNum;
+
+    fn add(self, other: &SfNum) -> SfNum {
+        let mut sum = SfNum::pair(self, other);
+        sum.reduce();
+        sum
+    }
+}
+
+impl Add for SfNum {
+    type Output = SfNum;
+
+    fn add(self, other: SfNum) -> SfNum {
+        &self + &other
+    }
+}
+
+/// Adds the numbers from left to right. Panics if there are none, as no
+/// snailfish number leaves another unchanged when added to it.
+impl Sum for SfNum {
+    fn sum<I: Iterator<Item=SfNum>>(iter: I) -> Self {
+        iter.reduce(|sum, n| sum + n).expect("cannot sum zero snailfish numbers")
+    }
+}
+
+impl<'a> Sum<&'a SfNum> for SfNum {
+    fn sum<I: Iterator<Item=&'a SfNum>>(iter: I) -> Self {
+        iter.cloned().sum()
+    }
+}
+
+fn parse_snailfish_number(line: &aoc::Line) -> Result<SfNum, ParseError> {
+    line.text
+        .parse()
+        .map_err(|e: ParseSfNumError| ParseError::new(line.number, e.index + 1, e.kind.to_string()))
+}
+
+fn parse_snailfish_numbers(input: impl BufRead) -> Result<Vec<SfNum>, ParseError> {
+    let mut lines = aoc::lines(input);
+    let mut numbers = vec![parse_snailfish_number(&lines.expect("a snailfish number")?)?];
+    for line in lines {
+        numbers.push(parse_snailfish_number(&line?)?);
+    }
+    Ok(numbers)
+}
+
+pub struct Day18;
+
+impl aoc::Solution for Day18 {
+    type Input = Vec<SfNum>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_snailfish_numbers(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        input.iter().sum::<SfNum>().magnitude()
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        let mut magnitude = 0;
+        for (i, a) in input.iter().enumerate() {
+            for (j, b) in input.iter().enumerate() {
+                if i != j {
+                    magnitude = magnitude.max((a + b).magnitude());
+                }
+            }
+        }
+        magnitude
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]
+";
+
+    #[test]
+    fn example() {
+        let input = Day18::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day18::part_1(&input), 4140);
+        assert_eq!(Day18::part_2(&input), 3993);
+    }
+
+    #[test]
+    fn adds_and_displays() {
+        let a = "[[[[4,3],4],4],[7,[[8,4],9]]]".parse::<SfNum>().unwrap();
+        let b = "[1,1]".parse::<SfNum>().unwrap();
+        assert_eq!((a + b).to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+        let sum = "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]";
+        assert_eq!(sum.parse::<SfNum>().unwrap().magnitude(), 4140);
+    }
+
+    #[test]
+    fn traces_reduction() {
+        let a = "[[[[4,3],4],4],[7,[[8,4],9]]]".parse::<SfNum>().unwrap();
+        let b = "[1,1]".parse::<SfNum>().unwrap();
+        let trace = SfNum::pair(&a, &b)
+            .reduction()
+            .map(|step| format!("{}: {}", step.action, step.number))
+            .collect::<Vec<_>>();
+        assert_eq!(trace, [
+            "explode at LLLL: [[[[0,7],4],[7,[[8,4],9]]],[1,1]]",
+            "explode at LRRL: [[[[0,7],4],[15,[0,13]]],[1,1]]",
+            "split at LRL: [[[[0,7],4],[[7,8],[0,13]]],[1,1]]",
+            "split at LRRR: [[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]",
+            "explode at LRRR: [[[[0,7],4],[[7,8],[6,0]]],[8,1]]"
+        ]);
+        let mut steps = SfNum::pair(&a, &b).reduction();
+        let split = steps.nth(2).unwrap().number;
+        assert_eq!(SfNum::parse_unreduced(&split.to_string()), Ok(split.clone()));
+        assert!(split.to_string().parse::<SfNum>().is_err());
+        let deep = SfNum::pair(&a, &b).to_string();
+        assert_eq!(SfNum::parse_unreduced(&deep), Ok(SfNum::pair(&a, &b)));
+        let error = SfNum::parse_unreduced(&format!("[{},1]", deep)).unwrap_err();
+        assert_eq!(error.kind, ParseSfNumErrorKind::TooDeep);
+        let error = SfNum::parse_unreduced("[1,99999999999]").unwrap_err();
+        assert_eq!(error, ParseSfNumError { index: 3, kind: ParseSfNumErrorKind::TooLarge });
+    }
+
+    #[test]
+    fn rejects_malformed_numbers() {
+        use ParseSfNumErrorKind::*;
+        let error = |s: &str| {
+            let error = s.parse::<SfNum>().unwrap_err();
+            (error.index, error.kind)
+        };
+        assert_eq!(error("[[1,2],3"), (8, UnclosedBracket(0)));
+        assert_eq!(error("[1,2]]"), (5, UnmatchedBracket));
+        assert_eq!(error("[],1]"), (1, UnmatchedBracket));
+        assert_eq!(error("[1 2]"), (2, MissingComma));
+        assert_eq!(error("[1,2,3]"), (4, ExpectedClosingBracket));
+        assert_eq!(error("[1,x]"), (3, ExpectedElement));
+        assert_eq!(error(""), (0, ExpectedElement));
+        assert_eq!(error("7"), (0, ExpectedPair));
+        assert_eq!(error("[1,2] "), (5, TrailingInput));
+        assert_eq!(error("[[[[[1,2],3],4],5],6]"), (4, TooDeep));
+        assert_eq!(error("[1,23]"), (3, MultiDigit));
+        assert_eq!(error("[é,1]"), (1, ExpectedElement));
+    }
+
+    #[test]
+    fn reports_error_columns() {
+        let error = Day18::parse("".as_bytes()).unwrap_err();
+        assert_eq!(error.line, 1);
+        assert_eq!(error.reason, "expected a snailfish number, found end of input");
+        let error = Day18::parse("[1,2]\n[[1,2],3\n".as_bytes()).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 9));
+        assert_eq!(error.reason, "unclosed `[` at index 0");
+    }
+}
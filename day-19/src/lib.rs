@@ -0,0 +1,474 @@
+use std::io::BufRead;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::{self, Display};
+use aoc::ParseError;
+
+mod rotation;
+
+pub use rotation::Rotation;
+
+pub type Point = (i32, i32, i32);
+
+fn parse_point(line: &aoc::Line) -> Result<Point, ParseError> {
+    let (x, rest) = line.split_once(&line.text, ",")?;
+    let (y, z) = line.split_once(rest, ",")?;
+    Ok((line.parse(x)?, line.parse(y)?, line.parse(z)?))
+}
+
+/// Each scanner's beacons, with the line number of its header.
+fn parse_scanners(input: impl BufRead) -> Result<(Vec<Vec<Point>>, Vec<usize>), ParseError> {
+    let mut input = aoc::lines(input);
+    let mut scanners = Vec::new();
+    let mut headers = Vec::new();
+    while let Some(header) = input.next() {
+        let header = header?;
+        if !header.text.starts_with("--- scanner ") {
+            return Err(header.error("expected a scanner header"));
+        }
+        headers.push(header.number);
+        let mut beacons = Vec::new();
+        for line in &mut input {
+            let line = line?;
+            if line.text.is_empty() {
+                break;
+            }
+            beacons.push(parse_point(&line)?);
+        }
+        scanners.push(beacons);
+    }
+    if scanners.is_empty() {
+        return Err(ParseError::new(1, 1, "expected at least one scanner"));
+    }
+    Ok((scanners, headers))
+}
+
+/// How many beacons two scanners must both detect to be aligned.
+const OVERLAP: usize = 12;
+
+/// Squared distances between the beacons of a scanner, which stay the same
+/// however the scanner is turned or moved.
+struct Fingerprint {
+    /// Every distance between two beacons, sorted.
+    dists: Vec<i64>,
+    /// The pairs of beacon indices at each distance.
+    pairs: HashMap<i64, Vec<(usize, usize)>>
+}
+
+fn squared_dist((x1, y1, z1): Point, (x2, y2, z2): Point) -> i64 {
+    let (dx, dy, dz) = ((x1 - x2) as i64, (y1 - y2) as i64, (z1 - z2) as i64);
+    dx * dx + dy * dy + dz * dz
+}
+
+fn fingerprint(scanner: &[Point]) -> Fingerprint {
+    let mut dists = Vec::new();
+    let mut pairs = HashMap::<_, Vec<_>>::new();
+    for (i, &a) in scanner.iter().enumerate() {
+        for (j, &b) in scanner.iter().enumerate().skip(i + 1) {
+            let dist = squared_dist(a, b);
+            dists.push(dist);
+            pairs.entry(dist).or_default().push((i, j));
+        }
+    }
+    dists.sort_unstable();
+    Fingerprint { dists, pairs }
+}
+
+/// The number of values two sorted lists have in common, counting repeats.
+fn common(a: &[i64], b: &[i64]) -> usize {
+    let (mut i, mut j, mut count) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                count += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    count
+}
+
+/// Pairs of beacon indices, one from each scanner, that could be the same
+/// beacon, most likely first. A beacon both scanners detect is the same
+/// distance from at least 11 other beacons in each, so this never misses a
+/// real overlap, and is empty for most pairs of scanners without one.
+fn candidate_matches(anchor: &Fingerprint, scanner: &Fingerprint) -> Vec<(usize, usize)> {
+    if common(&anchor.dists, &scanner.dists) < OVERLAP * (OVERLAP - 1) / 2 {
+        return Vec::new();
+    }
+    let mut votes = HashMap::new();
+    for (dist, scanner_pairs) in &scanner.pairs {
+        for &(a1, a2) in anchor.pairs.get(dist).map_or(&[][..], Vec::as_slice) {
+            for &(s1, s2) in scanner_pairs {
+                for pair in [(a1, s1), (a1, s2), (a2, s1), (a2, s2)] {
+                    *votes.entry(pair).or_insert(0) += 1;
+                }
+            }
+        }
+    }
+    let mut candidates = votes
+        .into_iter()
+        .filter(|&(_, votes)| votes >= OVERLAP - 1)
+        .collect::<Vec<_>>();
+    candidates.sort_unstable_by_key(|&(pair, votes)| (Reverse(votes), pair));
+    candidates.into_iter().map(|(pair, _)| pair).collect()
+}
+
+/// Places `scanner` so that at least 12 of its beacons land on `anchor`,
+/// trying only placements that put a `candidates` pair on the same spot.
+/// Returns its beacons and position in the anchor's frame and the
+/// [`Rotation`] that turns its readings into the anchor's.
+fn try_anchor(
+    anchor: &[Point],
+    scanner: &[Point],
+    candidates: &[(usize, usize)]
+) -> Option<(Vec<Point>, Point, Rotation)> {
+    if candidates.is_empty() {
+        return None;
+    }
+    let anchor_set = anchor.iter().copied().collect::<HashSet<_>>();
+    let rotations = Rotation::all().map(|rotation| {
+        let rotated = scanner.iter().map(|&p| rotation.apply(p)).collect::<Vec<_>>();
+        (rotation, rotated)
+    });
+    for &(a, s) in candidates {
+        let (apx, apy, apz) = anchor[a];
+        for (rotation, scanner) in &rotations {
+            let (spx, spy, spz) = scanner[s];
+            let (dx, dy, dz) = (apx - spx, apy - spy, apz - spz);
+            let common_points = scanner
+                .iter()
+                .filter(|&&(x, y, z)| anchor_set.contains(&(x + dx, y + dy, z + dz)))
+                .count();
+            if common_points >= OVERLAP {
+                let placed = scanner.iter().map(|&(x, y, z)| (x + dx, y + dy, z + dz)).collect();
+                return Some((placed, (dx, dy, dz), *rotation));
+            }
+        }
+    }
+    None
+}
+
+/// Where a scanner is relative to the reference scanner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alignment {
+    pub position: Point,
+    /// Turns this scanner's readings into the reference scanner's.
+    pub rotation: Rotation,
+    /// The beacons this scanner detects, relative to the reference scanner.
+    pub beacons: Vec<Point>
+}
+
+impl Alignment {
+    /// Moves a point this scanner reads into the reference scanner's frame.
+    pub fn place(&self, p: Point) -> Point {
+        let ((x, y, z), (dx, dy, dz)) = (self.rotation.apply(p), self.position);
+        (x + dx, y + dy, z + dz)
+    }
+}
+
+/// Scanners that share no chain of overlapping scanners with the reference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unreachable {
+    pub reference: usize,
+    pub scanners: Vec<usize>
+}
+
+impl Display for Unreachable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scanners = self.scanners.iter().map(ToString::to_string).collect::<Vec<_>>();
+        write!(
+            f,
+            "scanner(s) {} cannot be aligned with scanner {}",
+            scanners.join(", "),
+            self.reference
+        )
+    }
+}
+
+impl std::error::Error for Unreachable {}
+
+/// Aligns every scanner with `reference`, by searching the graph whose edges
+/// join scanners that detect at least 12 of the same beacons. Each pair of
+/// scanners is compared at most once. Panics if there is no scanner
+/// `reference`.
+pub fn align(scanners: &[Vec<Point>], reference: usize) -> Result<Vec<Alignment>, Unreachable> {
+    assert!(reference < scanners.len(), "there is no scanner {}", reference);
+    let mut aligned = vec![None; scanners.len()];
+    aligned[reference] = Some(Alignment {
+        position: (0, 0, 0),
+        rotation: Rotation::IDENTITY,
+        beacons: scanners[reference].clone()
+    });
+    let fingerprints = scanners.iter().map(|s| fingerprint(s)).collect::<Vec<_>>();
+    let mut queue = VecDeque::from([reference]);
+    while let Some(i) = queue.pop_front() {
+        // Aligned beacons keep their order, so they match the fingerprint.
+        let anchor = aligned[i].as_ref().expect("queued scanners are aligned").beacons.clone();
+        for (j, scanner) in scanners.iter().enumerate() {
+            if aligned[j].is_some() {
+                continue;
+            }
+            let candidates = candidate_matches(&fingerprints[i], &fingerprints[j]);
+            if let Some((beacons, position, rotation)) = try_anchor(&anchor, scanner, &candidates) {
+                aligned[j] = Some(Alignment { position, rotation, beacons });
+                queue.push_back(j);
+            }
+        }
+    }
+    let unreachable = (0..scanners.len()).filter(|&i| aligned[i].is_none()).collect::<Vec<_>>();
+    if !unreachable.is_empty() {
+        return Err(Unreachable { reference, scanners: unreachable });
+    }
+    Ok(aligned.into_iter().flatten().collect())
+}
+
+fn manhattan_dist((x1, y1, z1): Point, (x2, y2, z2): Point) -> i32 {
+    (x1 - x2).abs() + (y1 - y2).abs() + (z1 - z2).abs()
+}
+
+pub struct Day19;
+
+impl aoc::Solution for Day19 {
+    type Input = Vec<Alignment>;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        let (scanners, headers) = parse_scanners(input)?;
+        align(&scanners, 0).map_err(|e| ParseError::new(headers[e.scanners[0]], 1, e.to_string()))
+    }
+
+    fn part_1(input: &Self::Input) -> usize {
+        input
+            .iter()
+            .flat_map(|scanner| scanner.beacons.iter())
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    fn part_2(input: &Self::Input) -> i32 {
+        let mut max_dist = 0;
+        for a in input {
+            for b in input {
+                max_dist = max_dist.max(manhattan_dist(a.position, b.position));
+            }
+        }
+        max_dist
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+--- scanner 0 ---
+404,-588,-901
+528,-643,409
+-838,591,734
+390,-675,-793
+-537,-823,-458
+-485,-357,347
+-345,-311,381
+-661,-816,-575
+-876,649,763
+-618,-824,-621
+553,345,-567
+474,580,667
+-447,-329,318
+-584,868,-557
+544,-627,-890
+564,392,-477
+455,729,728
+-892,524,684
+-689,845,-530
+423,-701,434
+7,-33,-71
+630,319,-379
+443,580,662
+-789,900,-551
+459,-707,401
+
+--- scanner 1 ---
+686,422,578
+605,423,415
+515,917,-361
+-336,658,858
+95,138,22
+-476,619,847
+-340,-569,-846
+567,-361,727
+-460,603,-452
+669,-402,600
+729,430,532
+-500,-761,534
+-322,571,750
+-466,-666,-811
+-429,-592,574
+-355,545,-477
+703,-491,-529
+-328,-685,520
+413,935,-424
+-391,539,-444
+586,-435,557
+-364,-763,-893
+807,-499,-711
+755,-354,-619
+553,889,-390
+
+--- scanner 2 ---
+649,640,665
+682,-795,504
+-784,533,-524
+-644,584,-595
+-588,-843,648
+-30,6,44
+-674,560,763
+500,723,-460
+609,671,-379
+-555,-800,653
+-675,-892,-343
+697,-426,-610
+578,704,681
+493,664,-388
+-671,-858,530
+-667,343,800
+571,-461,-707
+-138,-166,112
+-889,563,-600
+646,-828,498
+640,759,510
+-630,509,768
+-681,-892,-333
+673,-379,-804
+-742,-814,-386
+577,-820,562
+
+--- scanner 3 ---
+-589,542,597
+605,-692,669
+-500,565,-823
+-660,373,557
+-458,-679,-417
+-488,449,543
+-626,468,-788
+338,-750,-386
+528,-832,-391
+562,-778,733
+-938,-730,414
+543,643,-506
+-524,371,-870
+407,773,750
+-104,29,83
+378,-903,-323
+-778,-728,485
+426,699,580
+-438,-605,-362
+-469,-447,-387
+509,732,623
+647,635,-688
+-868,-804,481
+614,-800,639
+595,780,-596
+
+--- scanner 4 ---
+727,592,562
+-293,-554,779
+441,611,-461
+-714,465,-776
+-743,427,-804
+-660,-479,-426
+832,-632,460
+927,-485,-438
+408,393,-506
+466,436,-512
+110,16,151
+-258,-428,682
+-393,719,612
+-211,-452,876
+808,-476,-593
+-575,615,604
+-485,667,467
+-680,325,-822
+-627,-443,-432
+872,-547,-609
+833,512,582
+807,604,487
+839,-516,451
+891,-625,532
+-652,-548,-490
+30,-46,-14
+";
+
+    #[test]
+    fn example() {
+        let input = Day19::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day19::part_1(&input), 79);
+        assert_eq!(Day19::part_2(&input), 3621);
+    }
+
+    #[test]
+    fn aligns_relative_to_the_reference() {
+        let (scanners, _) = parse_scanners(EXAMPLE.as_bytes()).unwrap();
+        let aligned = align(&scanners, 0).unwrap();
+        let positions = aligned.iter().map(|a| a.position).collect::<Vec<_>>();
+        assert_eq!(positions, [
+            (0, 0, 0),
+            (68, -1246, -43),
+            (1105, -1205, 1229),
+            (-92, -2380, -20),
+            (-20, -1133, 1061)
+        ]);
+        for (scanner, alignment) in scanners.iter().zip(&aligned) {
+            let placed = scanner.iter().map(|&p| alignment.place(p)).collect::<Vec<_>>();
+            assert_eq!(placed, alignment.beacons);
+        }
+        let reversed = &align(&scanners, 1).unwrap()[0];
+        assert_eq!(reversed.rotation, aligned[1].rotation.inverse());
+        let (x, y, z) = aligned[1].rotation.inverse().apply(aligned[1].position);
+        assert_eq!(reversed.position, (-x, -y, -z));
+    }
+
+    #[test]
+    fn fingerprints_find_real_matches() {
+        let (scanners, _) = parse_scanners(EXAMPLE.as_bytes()).unwrap();
+        let fingerprints = scanners.iter().map(|s| fingerprint(s)).collect::<Vec<_>>();
+        assert!(candidate_matches(&fingerprints[0], &fingerprints[2]).is_empty());
+        let candidates = candidate_matches(&fingerprints[0], &fingerprints[1]);
+        let aligned = align(&scanners, 0).unwrap();
+        let mut matches = 0;
+        for (i, a) in aligned[0].beacons.iter().enumerate() {
+            for (j, b) in aligned[1].beacons.iter().enumerate() {
+                if a == b {
+                    assert!(candidates.contains(&(i, j)));
+                    matches += 1;
+                }
+            }
+        }
+        assert_eq!(matches, 12);
+    }
+
+    #[test]
+    fn reports_unreachable_scanners() {
+        let input = format!("{}\n--- scanner 5 ---\n1,2,3\n4,5,6\n", EXAMPLE);
+        let (scanners, _) = parse_scanners(input.as_bytes()).unwrap();
+        assert_eq!(align(&scanners, 2), Err(Unreachable { reference: 2, scanners: vec![5] }));
+        let error = Day19::parse(input.as_bytes()).unwrap_err();
+        assert_eq!((error.line, error.column), (138, 1));
+        assert_eq!(error.reason, "scanner(s) 5 cannot be aligned with scanner 0");
+        let disconnected = "--- scanner 0 ---\n1,2,3\n\n--- scanner 1 ---\n4,5,6\n";
+        assert_eq!(Day19::parse(disconnected.as_bytes()).unwrap_err().line, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "there is no scanner 5")]
+    fn rejects_missing_reference() {
+        let (scanners, _) = parse_scanners(EXAMPLE.as_bytes()).unwrap();
+        let _ = align(&scanners, 5);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::Point;
+
+/// A turn that maps the axes onto the axes, as a 3x3 matrix with one 1 or -1
+/// in each row and column and a determinant of 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation([[i32; 3]; 3]);
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+
+    /// Every rotation, starting with the identity.
+    pub fn all() -> [Rotation; 24] {
+        let permutations = [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+        let mut rotations = [Rotation::IDENTITY; 24];
+        let mut count = 0;
+        for permutation in permutations {
+            for signs in 0..8 {
+                let mut matrix = [[0; 3]; 3];
+                for (row, &column) in permutation.iter().enumerate() {
+                    matrix[row][column] = if signs >> row & 1 == 0 { 1 } else { -1 };
+                }
+                // The other half of the matrices are reflections.
+                if determinant(matrix) == 1 {
+                    rotations[count] = Rotation(matrix);
+                    count += 1;
+                }
+            }
+        }
+        rotations
+    }
+
+    pub fn matrix(self) -> [[i32; 3]; 3] {
+        self.0
+    }
+
+    pub fn apply(self, (x, y, z): Point) -> Point {
+        let [a, b, c] = self.0.map(|row| row[0] * x + row[1] * y + row[2] * z);
+        (a, b, c)
+    }
+
+    /// The rotation that applies `first`, then this one.
+    pub fn compose(self, first: Rotation) -> Rotation {
+        let mut matrix = [[0; 3]; 3];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = (0..3).map(|k| self.0[i][k] * first.0[k][j]).sum();
+            }
+        }
+        Rotation(matrix)
+    }
+
+    /// The rotation that undoes this one, which is its transpose.
+    pub fn inverse(self) -> Rotation {
+        let m = self.0;
+        Rotation([0, 1, 2].map(|i| [m[0][i], m[1][i], m[2][i]]))
+    }
+}
+
+fn determinant(m: [[i32; 3]; 3]) -> i32 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use super::*;
+
+    #[test]
+    fn finds_every_orientation() {
+        let all = Rotation::all();
+        assert_eq!(all[0], Rotation::IDENTITY);
+        let images = all.iter().map(|r| r.apply((1, 2, 3))).collect::<HashSet<_>>();
+        assert_eq!(images.len(), 24);
+        assert!(all.iter().all(|&r| determinant(r.matrix()) == 1));
+    }
+
+    #[test]
+    fn composes_and_inverts() {
+        let p = (5, -7, 11);
+        for a in Rotation::all() {
+            assert_eq!(a.compose(a.inverse()), Rotation::IDENTITY);
+            assert_eq!(a.inverse().apply(a.apply(p)), p);
+            for b in Rotation::all() {
+                assert_eq!(a.compose(b).apply(p), a.apply(b.apply(p)));
+                assert!(Rotation::all().contains(&a.compose(b)));
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::io::BufRead;
+use aoc::ParseError;
+
+pub enum Command {
+    Forward(i32),
+    Up(i32),
+    Down(i32)
+}
+
+fn parse_inputs(input: impl BufRead) -> Result<Vec<Command>, ParseError> {
+    aoc::lines(input)
+        .map(|line| {
+            let line = line?;
+            let (kind, n) = line.split_once(&line.text, " ")?;
+            let n = line.parse(n)?;
+            Ok(match kind {
+                "forward" => Command::Forward(n),
+                "up"      => Command::Up(n),
+                "down"    => Command::Down(n),
+                _ => return Err(line.error_at(kind, format!("unknown command `{}`", kind)))
+            })
+        })
+        .collect()
+}
+
+pub struct Day2;
+
+impl aoc::Solution for Day2 {
+    type Input = Vec<Command>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_inputs(input)
+    }
+
+    fn part_1(input: &Self::Input) -> i32 {
+        let mut h_pos = 0;
+        let mut depth = 0;
+        for command in input {
+            match *command {
+                Command::Forward(n) => h_pos += n,
+                Command::Up(n) =>      depth -= n,
+                Command::Down(n) =>    depth += n
+            }
+        }
+        h_pos * depth
+    }
+
+    fn part_2(input: &Self::Input) -> i32 {
+        let mut aim = 0;
+        let mut h_pos = 0;
+        let mut depth = 0;
+        for command in input {
+            match *command {
+                Command::Forward(n) => {
+                    h_pos += n;
+                    depth += aim * n;
+                },
+                Command::Up(n) => aim -= n,
+                Command::Down(n) => aim += n
+            }
+        }
+        h_pos * depth
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2
+";
+
+    #[test]
+    fn example() {
+        let input = Day2::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day2::part_1(&input), 150);
+        assert_eq!(Day2::part_2(&input), 900);
+    }
+}
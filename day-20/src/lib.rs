@@ -0,0 +1,126 @@
+use std::io::BufRead;
+use aoc::ParseError;
+use aoc::grid::Grid;
+
+#[derive(Clone)]
+pub struct Image {
+    background_is_white: bool,
+    pixels: Grid<bool>
+}
+
+impl Image {
+    fn get(&self, x: isize, y: isize) -> bool {
+        match (usize::try_from(x), usize::try_from(y)) {
+            (Ok(x), Ok(y)) => self.pixels.get((x, y)).copied().unwrap_or(self.background_is_white),
+            _ => self.background_is_white
+        }
+    }
+
+    fn calculate_pixel(&self, map: &[bool; 512], (x, y): (isize, isize)) -> bool {
+        let neighbors = [
+            (x - 1, y - 1), (x    , y - 1), (x + 1, y - 1),
+            (x - 1, y    ), (x    , y    ), (x + 1, y    ),
+            (x - 1, y + 1), (x    , y + 1), (x + 1, y + 1),
+        ];
+        let index = neighbors
+            .into_iter()
+            .zip((0..9).rev())
+            .fold(0, |n, ((x, y), i)| n | ((self.get(x, y) as usize) << i));
+        map[index]
+    }
+
+    fn enhance(&self, map: &[bool; 512]) -> Image {
+        // Pixels next to the image can light up too, so it grows by one on each side.
+        let width = self.pixels.width() + 2;
+        let height = self.pixels.height() + 2;
+        let pixels = Grid::from_fn(width, height, |(x, y)| {
+            self.calculate_pixel(map, (x as isize - 1, y as isize - 1))
+        });
+        Image {
+            background_is_white: self.background_is_white ^ map[0],
+            pixels
+        }
+    }
+
+    fn lit_pixels(&self) -> usize {
+        self.pixels.values().filter(|&&lit| lit).count()
+    }
+}
+
+fn parse_input(input: impl BufRead) -> Result<([bool; 512], Image), ParseError> {
+    fn pixel(c: char) -> Option<bool> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None
+        }
+    }
+    let mut lines = aoc::lines(input);
+    let line = lines.expect("an image enhancement algorithm")?;
+    let map = line.text
+        .char_indices()
+        .map(|(i, c)| pixel(c).ok_or_else(|| line.error_at(&line.text[i..], "expected `#` or `.`")))
+        .collect::<Result<Vec<_>, _>>()?
+        .try_into()
+        .map_err(|map: Vec<_>| line.error(format!("expected 512 pixels, found {}", map.len())))?;
+    let separator = lines.expect("a blank line")?;
+    if !separator.text.is_empty() {
+        return Err(separator.error("expected a blank line"));
+    }
+    let first_row = lines.expect("an image")?;
+    let pixels = Grid::from_lines(std::iter::once(Ok(first_row)).chain(lines), pixel, "`#` or `.`")?;
+    let image = Image {
+        background_is_white: false,
+        pixels
+    };
+    Ok((map, image))
+}
+
+pub struct Day20;
+
+impl aoc::Solution for Day20 {
+    type Input = ([bool; 512], Image);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> usize {
+        let (map, image) = input;
+        image.enhance(map).enhance(map).lit_pixels()
+    }
+
+    fn part_2(input: &Self::Input) -> usize {
+        let (map, image) = input;
+        let mut image = image.clone();
+        for _ in 0..50 {
+            image = image.enhance(map);
+        }
+        image.lit_pixels()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###
+";
+
+    #[test]
+    fn example() {
+        let input = Day20::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day20::part_1(&input), 35);
+        assert_eq!(Day20::part_2(&input), 3351);
+    }
+}
@@ -0,0 +1,121 @@
+use std::io::BufRead;
+use std::collections::HashMap;
+use aoc::ParseError;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct PlayerState {
+    pawn: u32,
+    score: u32
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct GameState {
+    p1: PlayerState,
+    p2: PlayerState,
+    is_p1_turn: bool,
+}
+
+impl GameState {
+    fn next(&self, die_result: u32) -> Self {
+        let mut new = *self;
+        let player = if new.is_p1_turn {
+            &mut new.p1
+        } else {
+            &mut new.p2
+        };
+        player.pawn = ((player.pawn + die_result - 1) % 10) + 1;
+        player.score += player.pawn;
+        new.is_p1_turn = !new.is_p1_turn;
+        new
+    }
+}
+
+fn parse_game_state(input: impl BufRead) -> Result<GameState, ParseError> {
+    let mut input = aoc::lines(input);
+    let mut parse_player = |player| {
+        let line = input.expect(&format!("player {}'s starting position", player))?;
+        let (_, position) = line.split_once(&line.text, ": ")?;
+        let pawn = line.parse(position)?;
+        if !(1..=10).contains(&pawn) {
+            return Err(line.error_at(position, "starting positions must be between 1 and 10"));
+        }
+        Ok(PlayerState {
+            pawn,
+            score: 0
+        })
+    };
+    Ok(GameState {
+        p1: parse_player(1)?,
+        p2: parse_player(2)?,
+        is_p1_turn: true
+    })
+}
+
+pub struct Day21;
+
+impl aoc::Solution for Day21 {
+    type Input = GameState;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_game_state(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        let mut game = *input;
+        let mut die = (1..=100).cycle();
+        let mut die_rolls = 0;
+        while game.p1.score.max(game.p2.score) < 1000 {
+            game = game.next((&mut die).take(3).sum());
+            die_rolls += 3;
+        }
+        game.p1.score.min(game.p2.score) * die_rolls
+    }
+
+    fn part_2(input: &Self::Input) -> u64 {
+        let mut universes = HashMap::new();
+        universes.insert(*input, 1);
+        let mut p1_wins = 0;
+        let mut p2_wins = 0;
+        while !universes.is_empty() {
+            let mut next = HashMap::new();
+            for (universe, count) in universes {
+                for d1 in 1..=3 {
+                    for d2 in 1..=3 {
+                        for d3 in 1..=3 {
+                            let universe = universe.next(d1 + d2 + d3);
+                            if universe.p1.score >= 21 {
+                                p1_wins += count;
+                            } else if universe.p2.score >= 21 {
+                                p2_wins += count;
+                            } else {
+                                *next.entry(universe).or_default() += count;
+                            }
+                        }
+                    }
+                }
+            }
+            universes = next;
+        }
+        p1_wins.max(p2_wins)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Player 1 starting position: 4
+Player 2 starting position: 8
+";
+
+    #[test]
+    fn example() {
+        let input = Day21::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day21::part_1(&input), 739785);
+        assert_eq!(Day21::part_2(&input), 444356092776315);
+    }
+}
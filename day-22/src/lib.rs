@@ -0,0 +1,224 @@
+use std::io::BufRead;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use aoc::ParseError;
+
+type Cuboid = [RangeInclusive<i32>; 3];
+
+fn parse_instructions(input: impl BufRead) -> Result<Vec<(bool, Cuboid)>, ParseError> {
+    aoc::lines(input)
+        .map(|instruction| {
+            let instruction = instruction?;
+            let (state, dimensions) = instruction.split_once(&instruction.text, " ")?;
+            let state = match state {
+                "on" => true,
+                "off" => false,
+                _ => return Err(instruction.error_at(state, "expected `on` or `off`"))
+            };
+            let mut dimensions = dimensions.split(',');
+            let mut parse_range = |axis: &str| -> Result<RangeInclusive<i32>, ParseError> {
+                let range = dimensions
+                    .next()
+                    .ok_or_else(|| instruction.error_at_end(format!("expected `{}`", axis)))?;
+                let range = instruction.strip_prefix(range, axis)?;
+                let (from, to) = instruction.split_once(range, "..")?;
+                let (from, to) = (instruction.parse(from)?, instruction.parse(to)?);
+                if from > to {
+                    return Err(instruction.error_at(range, "range ends before it starts"));
+                }
+                Ok(from..=to)
+            };
+            let x = parse_range("x=")?;
+            let y = parse_range("y=")?;
+            let z = parse_range("z=")?;
+            if let Some(extra) = dimensions.next() {
+                return Err(instruction.error_at(extra, "expected only `x`, `y` and `z` ranges"));
+            }
+            Ok((state, [x, y, z]))
+        })
+        .collect()
+}
+
+fn constrain(r: &RangeInclusive<i32>) -> RangeInclusive<i32> {
+    (*r.start()).max(-50)..=(*r.end()).min(50)
+}
+
+fn overlap(c1: &Cuboid, c2: &Cuboid) -> Option<Cuboid> {
+    let mut overlap = [0..=0, 0..=0, 0..=0];
+    let ranges = overlap.iter_mut().zip(c1.iter().zip(c2.iter()));
+    for (o, (r1, r2)) in ranges {
+        if r2.start() > r1.end() || r1.start() > r2.end() {
+            return None;
+        }
+        *o = *r1.start().max(r2.start())..=*r1.end().min(r2.end());
+    }
+    Some(overlap)
+}
+
+fn axis_length(axis: &RangeInclusive<i32>) -> i32 {
+    *axis.end() - *axis.start() + 1
+}
+
+fn volume([x, y, z]: &Cuboid) -> u64 {
+    axis_length(x) as u64 * axis_length(y) as u64 * axis_length(z)as u64 
+}
+
+pub struct Day22;
+
+impl aoc::Solution for Day22 {
+    type Input = Vec<(bool, Cuboid)>;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part_1(input: &Self::Input) -> usize {
+        let mut cubes = HashSet::new();
+        for (state, [x, y, z]) in input {
+            for x in constrain(x) {
+                for y in constrain(y) {
+                    for z in constrain(z) {
+                        let cube = [x, y, z];
+                        if *state {
+                            cubes.insert(cube);
+                        } else {
+                            cubes.remove(&cube);
+                        }
+                    }
+                }
+            }
+        }
+        cubes.len()
+    }
+
+    fn part_2(input: &Self::Input) -> u64 {
+        let mut cuboids = HashSet::new();
+        for (is_adding, cuboid) in input.iter().cloned() {
+            let mut new_cuboids = HashSet::new();
+            let mut new_cuboid_is_encased = false;
+            for intersecting in cuboids {
+                if let Some(overlap) = overlap(&intersecting, &cuboid) {
+                    if is_adding && overlap == cuboid {
+                        //The new cuboid adds, but it is completely encased.
+                        //Adding it is thus useless.
+                        new_cuboid_is_encased = true;
+                        break;
+                    }
+                    if overlap == intersecting {
+                        //The new cuboid completely encases the old one, so just ignore it.
+                        //This is true regardless of whether the new one adds or subtracts.
+                        continue;
+                    }
+                    let [ix, iy, iz] = intersecting;
+                    let [ox, oy, oz] = overlap;
+                    if ix.start() < ox.start() {
+                        let from = *ix.start();
+                        let to = *ox.start() - 1;
+                        new_cuboids.insert([from..=to, iy.clone(), iz.clone()]);
+                    }
+                    if ox.end() < ix.end() {
+                        let from = *ox.end() + 1;
+                        let to = *ix.end();
+                        new_cuboids.insert([from..=to, iy.clone(), iz.clone()]);
+                    }
+
+                    if iy.start() < oy.start() {
+                        let from = *iy.start();
+                        let to = *oy.start() - 1;
+                        new_cuboids.insert([ox.clone(), from..=to, iz.clone()]);
+                    }
+                    if oy.end() < iy.end() {
+                        let from = *oy.end() + 1;
+                        let to = *iy.end();
+                        new_cuboids.insert([ox.clone(), from..=to, iz.clone()]);
+                    }
+
+                    if iz.start() < oz.start() {
+                        let from = *iz.start();
+                        let to = *oz.start() - 1;
+                        new_cuboids.insert([ox.clone(), oy.clone(), from..=to]);
+                    }
+                    if oz.end() < iz.end() {
+                        let from = *oz.end() + 1;
+                        let to = *iz.end();
+                        new_cuboids.insert([ox.clone(), oy.clone(), from..=to]);
+                    }
+                } else {
+                    //This doesn't overlap, so just add it unchanged.
+                    new_cuboids.insert(intersecting);
+                }
+            }
+            if is_adding && !new_cuboid_is_encased {
+                new_cuboids.insert(cuboid);
+            }
+            cuboids = new_cuboids;
+        }
+        cuboids.iter().map(volume).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const SMALL_EXAMPLE: &str = "\
+on x=10..12,y=10..12,z=10..12
+on x=11..13,y=11..13,z=11..13
+off x=9..11,y=9..11,z=9..11
+on x=10..10,y=10..10,z=10..10
+";
+
+    const LARGER_EXAMPLE: &str = "\
+on x=-20..26,y=-36..17,z=-47..7
+on x=-20..33,y=-21..23,z=-26..28
+on x=-22..28,y=-29..23,z=-38..16
+on x=-46..7,y=-6..46,z=-50..-1
+on x=-49..1,y=-3..46,z=-24..28
+on x=2..47,y=-22..22,z=-23..27
+on x=-27..23,y=-28..26,z=-21..29
+on x=-39..5,y=-6..47,z=-3..44
+on x=-30..21,y=-8..43,z=-13..34
+on x=-22..26,y=-27..20,z=-29..19
+off x=-48..-32,y=26..41,z=-47..-37
+on x=-12..35,y=6..50,z=-50..-2
+off x=-48..-32,y=-32..-16,z=-15..-5
+on x=-18..26,y=-33..15,z=-7..46
+off x=-40..-22,y=-38..-28,z=23..41
+on x=-16..35,y=-41..10,z=-47..6
+off x=-32..-23,y=11..30,z=-14..3
+on x=-49..-5,y=-3..45,z=-29..18
+off x=18..30,y=-20..-8,z=-3..13
+on x=-41..9,y=-7..43,z=-33..15
+on x=-54112..-39298,y=-85059..-49293,z=-27449..7877
+on x=967..23432,y=45373..81175,z=27513..53682
+";
+
+    #[test]
+    fn small_example() {
+        let input = Day22::parse(SMALL_EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day22::part_1(&input), 39);
+        assert_eq!(Day22::part_2(&input), 39);
+    }
+
+    #[test]
+    fn larger_example() {
+        let input = Day22::parse(LARGER_EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day22::part_1(&input), 590784);
+    }
+
+    #[test]
+    fn rejects_bad_ranges() {
+        let error = Day22::parse("on x=1..2,y=3..4,z=5..6,w=7..8\n".as_bytes()).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 25));
+        assert_eq!(error.reason, "expected only `x`, `y` and `z` ranges");
+        let error = Day22::parse("on x=1..2,y=3..4,z=5..6,\n".as_bytes()).unwrap_err();
+        assert_eq!(error.column, 25);
+        let reversed = "on x=1..2,y=3..4,z=5..6\noff x=1..2,y=4..3,z=5..6\n";
+        let error = Day22::parse(reversed.as_bytes()).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 14));
+        assert_eq!(error.reason, "range ends before it starts");
+    }
+}
@@ -1,5 +1,6 @@
 use std::io::BufRead;
 use std::collections::HashMap;
+use aoc::ParseError;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 enum Amphipod {
@@ -21,7 +22,7 @@ impl Amphipod {
 }
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
-struct Burrow {
+pub struct Burrow {
     hallway: [Option<Amphipod>; 7],
     rooms: [Vec<Amphipod>; 4],
     max_room_size: usize
@@ -43,10 +44,8 @@ impl Burrow {
             Amphipod::C => 2,
             Amphipod::D => 3
         };
-        if room == correct_room {
-            if room_vec.iter().all(|&a| a == amphipod) {
-                return None.into_iter().flatten();
-            }
+        if room == correct_room && room_vec.iter().all(|&a| a == amphipod) {
+            return None.into_iter().flatten();
         }
 
         let mid = 2 + room;
@@ -64,7 +63,7 @@ impl Burrow {
         let mut new = self.clone();
         let true_room_pos = TRUE_ROOM_POSITIONS[room];
         let true_slot_pos = TRUE_SLOT_POSITIONS[slot];
-        let room_to_slot_dist = (true_room_pos - true_slot_pos).abs() as u32;
+        let room_to_slot_dist = (true_room_pos - true_slot_pos).unsigned_abs();
         let room_exit_dist = (self.max_room_size - new.rooms[room].len()) as u32 + 1;
         let dist = room_exit_dist + room_to_slot_dist;
         let amphipod = new.rooms[room].pop().unwrap();
@@ -99,7 +98,7 @@ impl Burrow {
         let mut new = self.clone();
         let true_room_pos = TRUE_ROOM_POSITIONS[room];
         let true_slot_pos = TRUE_SLOT_POSITIONS[slot];
-        let slot_to_room_dist = (true_room_pos - true_slot_pos).abs() as u32;
+        let slot_to_room_dist = (true_room_pos - true_slot_pos).unsigned_abs();
         let room_enter_dist = (self.max_room_size - new.rooms[room].len()) as u32;
         let dist = room_enter_dist + slot_to_room_dist;
         let amphipod = new.hallway[slot].take().unwrap();
@@ -143,47 +142,86 @@ impl Burrow {
     }
 }
 
-fn parse_inputs(input: impl BufRead) -> Burrow {
-    let mut input = input.lines().map(Result::unwrap);
-    input.next();
-    input.next();
-    let mut burrow = Burrow::default();
-    burrow.max_room_size = 2;
-    for line in input.take(2) {
-        let amphipods = line
-            .chars()
-            .filter(|c| matches!(c, 'A' | 'B' | 'C' | 'D'))
-            .enumerate();
-        for (i, amphipod) in amphipods {
-            burrow.rooms[i].insert(0, match amphipod {
+fn parse_inputs(input: impl BufRead) -> Result<Burrow, ParseError> {
+    let mut input = aoc::lines(input);
+    input.expect("the burrow's top wall")?;
+    input.expect("the hallway")?;
+    let mut burrow = Burrow {
+        max_room_size: 2,
+        ..Default::default()
+    };
+    for _ in 0..2 {
+        let line = input.expect("a row of side rooms")?;
+        let mut room = 0;
+        for (i, c) in line.text.char_indices() {
+            let amphipod = match c {
                 'A' => Amphipod::A,
                 'B' => Amphipod::B,
                 'C' => Amphipod::C,
                 'D' => Amphipod::D,
-                _ => panic!()
-            });
+                '#' | ' ' => continue,
+                _ => return Err(line.error_at(&line.text[i..], "expected an amphipod or a wall"))
+            };
+            if room == burrow.rooms.len() {
+                return Err(line.error_at(&line.text[i..], "expected 4 side rooms"));
+            }
+            burrow.rooms[room].insert(0, amphipod);
+            room += 1;
+        }
+        if room < burrow.rooms.len() {
+            return Err(line.error_at_end("expected 4 side rooms"));
         }
     }
-    burrow
+    Ok(burrow)
 }
 
-fn part_1(input: impl BufRead) -> u32 {
-    let burrow = parse_inputs(input);
-    burrow.optimal_energy( &mut HashMap::new()).unwrap()
-}
+pub struct Day23;
+
+impl aoc::Solution for Day23 {
+    type Input = Burrow;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_inputs(input)
+    }
 
-fn part_2(input: impl BufRead) -> u32 {
-    let mut burrow = parse_inputs(input);
-    burrow.max_room_size = 4;
-    burrow.rooms[0].insert(1, Amphipod::D);
-    burrow.rooms[0].insert(1, Amphipod::D);
-    burrow.rooms[1].insert(1, Amphipod::C);
-    burrow.rooms[1].insert(1, Amphipod::B);
-    burrow.rooms[2].insert(1, Amphipod::B);
-    burrow.rooms[2].insert(1, Amphipod::A);
-    burrow.rooms[3].insert(1, Amphipod::A);
-    burrow.rooms[3].insert(1, Amphipod::C);
-    burrow.optimal_energy( &mut HashMap::new()).unwrap()
+    fn part_1(input: &Self::Input) -> u32 {
+        input.optimal_energy(&mut HashMap::new()).unwrap()
+    }
+
+    fn part_2(input: &Self::Input) -> u32 {
+        let mut burrow = input.clone();
+        burrow.max_room_size = 4;
+        burrow.rooms[0].insert(1, Amphipod::D);
+        burrow.rooms[0].insert(1, Amphipod::D);
+        burrow.rooms[1].insert(1, Amphipod::C);
+        burrow.rooms[1].insert(1, Amphipod::B);
+        burrow.rooms[2].insert(1, Amphipod::B);
+        burrow.rooms[2].insert(1, Amphipod::A);
+        burrow.rooms[3].insert(1, Amphipod::A);
+        burrow.rooms[3].insert(1, Amphipod::C);
+        burrow.optimal_energy(&mut HashMap::new()).unwrap()
+    }
 }
 
-aoc::main!();
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########
+";
+
+    #[test]
+    fn example() {
+        let input = Day23::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day23::part_1(&input), 12521);
+        assert_eq!(Day23::part_2(&input), 44169);
+    }
+}
@@ -0,0 +1,131 @@
+use std::io::BufRead;
+use std::cmp::Ordering;
+use aoc::ParseError;
+
+pub struct DiagnosticReport {
+    record_bits: usize,
+    records: Vec<i32>
+}
+
+fn parse_inputs(input: impl BufRead) -> Result<DiagnosticReport, ParseError> {
+    let mut record_bits = None;
+    let records = aoc::lines(input)
+        .map(|line| {
+            let line = line?;
+            if let Some(i) = line.text.find(|c| !matches!(c, '0' | '1')) {
+                return Err(line.error_at(&line.text[i..], "expected a binary digit"));
+            }
+            let bits = *record_bits.get_or_insert(line.text.len());
+            if line.text.len() != bits {
+                return Err(line.error(format!("expected {} bits like the first record", bits)));
+            }
+            i32::from_str_radix(&line.text, 2)
+                .map_err(|e| line.error(format!("invalid binary number: {}", e)))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(DiagnosticReport {
+        record_bits: record_bits.unwrap_or(0),
+        records
+    })
+}
+
+fn bitset_contains(set: i32, i: usize) -> bool {
+    (set & (1 << i)) != 0
+}
+
+fn compare_ones_to_zeroes(records: &[i32], i: usize) -> Ordering {
+    records
+        .iter()
+        .map(|&n| if bitset_contains(n, i) { 1 } else { -1 })
+        .sum::<i32>()
+        .cmp(&0)
+}
+
+fn find_value_by(report: &DiagnosticReport, f: impl Fn(&[i32], usize) -> bool) -> i32 {
+    let mut records = report.records.clone();
+    for i in (0..report.record_bits).rev() {
+        let required_bit = f(&records, i);
+        records.retain(|&n| bitset_contains(n, i) == required_bit);
+        if records.len() == 1 {
+            return records[0];
+        }
+    }
+    panic!()
+}
+
+pub struct Day3;
+
+impl aoc::Solution for Day3 {
+    type Input = DiagnosticReport;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_inputs(input)
+    }
+
+    fn part_1(input: &Self::Input) -> i32 {
+        let mut occurrences = vec![0; input.record_bits];
+        for &record in &input.records {
+            for (i, occurrence) in occurrences.iter_mut().enumerate() {
+                if bitset_contains(record, i) {
+                    *occurrence += 1;
+                } else {
+                    *occurrence -= 1;
+                }
+            }
+        }
+        let mut gamma = 0;
+        for (i, &n) in occurrences.iter().enumerate() {
+            if n > 0 {
+                gamma |= 1 << i;
+            }
+        }
+        let epsilon = gamma ^ ((1 << input.record_bits) - 1);
+        gamma * epsilon
+    }
+
+    fn part_2(input: &Self::Input) -> i32 {
+        let oxygen = find_value_by(input, |records, i| {
+            match compare_ones_to_zeroes(records, i) {
+                Ordering::Greater | Ordering::Equal => true,
+                Ordering::Less => false
+            }
+        });
+        let co2 = find_value_by(input, |records, i| {
+            match compare_ones_to_zeroes(records, i) {
+                Ordering::Less => true,
+                Ordering::Greater | Ordering::Equal => false
+            }
+        });
+        oxygen * co2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010
+";
+
+    #[test]
+    fn example() {
+        let input = Day3::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day3::part_1(&input), 198);
+        assert_eq!(Day3::part_2(&input), 230);
+    }
+}
@@ -0,0 +1,145 @@
+use std::io::BufRead;
+use aoc::ParseError;
+
+#[derive(Clone)]
+pub struct BingoGame {
+    numbers: Vec<i32>,
+    boards: Vec<MarkedBoard>
+}
+
+#[derive(Default, Clone)]
+struct MarkedBoard([[(i32, bool); 5]; 5]);
+
+impl MarkedBoard {
+    fn mark(&mut self, number: i32) {
+        let matching = self.0
+            .iter_mut()
+            .flatten()
+            .filter(|(n, _)| *n == number);
+        for (_, marked) in matching {
+            *marked = true;
+        }
+    }
+
+    fn won(&self) -> bool {
+        self.0.iter().any(|&r| r.iter().all(|(_, m)| *m))
+            || (0..5).any(|c| self.0.iter().all(|r| r[c].1))
+    }
+
+    fn score(&self) -> i32 {
+        self.0.iter()
+            .flatten()
+            .filter_map(|&(n, m)| if !m { Some(n) } else { None })
+            .sum()
+    }
+}
+
+fn parse_inputs(input: impl BufRead) -> Result<BingoGame, ParseError> {
+    let mut lines = aoc::lines(input);
+    let line = lines.expect("the drawn numbers")?;
+    let numbers = line.text
+        .split(',')
+        .map(|n| line.parse(n))
+        .collect::<Result<_, _>>()?;
+    let mut boards = Vec::new();
+    while let Some(separator) = lines.next() {
+        let separator = separator?;
+        if !separator.text.is_empty() {
+            return Err(separator.error("expected a blank line before each board"));
+        }
+        let mut board = MarkedBoard::default();
+        for row in &mut board.0 {
+            let line = lines.expect("a board row")?;
+            let mut numbers = line.text.split_ascii_whitespace();
+            for cell in row.iter_mut() {
+                let number = numbers
+                    .next()
+                    .ok_or_else(|| line.error_at_end("expected 5 numbers per row"))?;
+                *cell = (line.parse(number)?, false);
+            }
+            if let Some(extra) = numbers.next() {
+                return Err(line.error_at(extra, "expected 5 numbers per row"));
+            }
+        }
+        boards.push(board);
+    }
+    Ok(BingoGame {
+        numbers,
+        boards
+    })
+}
+
+pub struct Day4;
+
+impl aoc::Solution for Day4 {
+    type Input = BingoGame;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_inputs(input)
+    }
+
+    fn part_1(input: &Self::Input) -> i32 {
+        let mut input = input.clone();
+        for &number in &input.numbers {
+            for board in &mut input.boards {
+                board.mark(number);
+                if board.won() {
+                    return board.score() * number;
+                }
+            }
+        }
+        panic!()
+    }
+
+    fn part_2(input: &Self::Input) -> i32 {
+        let mut input = input.clone();
+        for &number in &input.numbers {
+            for board in &mut input.boards {
+                board.mark(number);
+            }
+            if input.boards.len() > 1 {
+                input.boards.retain(|b| !b.won());
+            } else if input.boards[0].won() {
+                return input.boards[0].score() * number;
+            }
+        }
+        panic!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+";
+
+    #[test]
+    fn example() {
+        let input = Day4::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day4::part_1(&input), 4512);
+        assert_eq!(Day4::part_2(&input), 1924);
+    }
+}
@@ -0,0 +1,98 @@
+use std::io::BufRead;
+use std::collections::HashMap;
+use aoc::ParseError;
+
+type Point = (i32, i32);
+
+fn parse_point(line: &aoc::Line, point: &str) -> Result<Point, ParseError> {
+    let (x, y) = line.split_once(point, ",")?;
+    Ok((line.parse(x)?, line.parse(y)?))
+}
+
+type Line = (Point, Point);
+
+fn parse_lines(input: impl BufRead) -> Result<Vec<Line>, ParseError> {
+    aoc::lines(input)
+        .map(|line| {
+            let line = line?;
+            let (from, to) = line.split_once(&line.text, " -> ")?;
+            Ok((parse_point(&line, from)?, parse_point(&line, to)?))
+        })
+        .collect()
+}
+
+fn update_points(points: &mut HashMap<Point, u32>, new_points: impl Iterator<Item=Point>) {
+    for point in new_points {
+        *points.entry(point).or_default() += 1;
+    }
+}
+
+pub struct Day5;
+
+impl aoc::Solution for Day5 {
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part_1(input: &Self::Input) -> usize {
+        let mut points = HashMap::new();
+        for &((x1, y1), (x2, y2)) in input {
+            if x1 == x2 {
+                update_points(&mut points, (y1.min(y2)..=y1.max(y2)).map(|y| (x1, y)));
+            } else if y1 == y2 {
+                update_points(&mut points, (x1.min(x2)..=x1.max(x2)).map(|x| (x, y1)));
+            }
+        }
+        points.values().filter(|&&n| n > 1).count()
+    }
+
+    fn part_2(input: &Self::Input) -> usize {
+        let mut points = HashMap::new();
+        for &((x1, y1), (x2, y2)) in input {
+            if x1 == x2 {
+                update_points(&mut points, (y1.min(y2)..=y1.max(y2)).map(|y| (x1, y)));
+            } else if y1 == y2 {
+                update_points(&mut points, (x1.min(x2)..=x1.max(x2)).map(|x| (x, y1)));
+            } else {
+                let x = x1.min(x2)..=x1.max(x2);
+                let y = y1.min(y2)..=y1.max(y2);
+                if (x1 < x2) == (y1 < y2) {
+                    update_points(&mut points, x.zip(y));
+                } else {
+                    update_points(&mut points, x.rev().zip(y));
+                }
+            }
+        }
+        points.values().filter(|&&n| n > 1).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2
+";
+
+    #[test]
+    fn example() {
+        let input = Day5::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day5::part_1(&input), 5);
+        assert_eq!(Day5::part_2(&input), 12);
+    }
+}
@@ -0,0 +1,68 @@
+use std::io::BufRead;
+use aoc::ParseError;
+
+fn parse_ages(input: impl BufRead) -> Result<Vec<u64>, ParseError> {
+    let line = aoc::lines(input).expect("the fish ages")?;
+    line.text
+        .split(',')
+        .map(|n| {
+            let age = line.parse(n)?;
+            if age > 8 {
+                return Err(line.error_at(n, "fish ages must be at most 8"));
+            }
+            Ok(age)
+        })
+        .collect()
+}
+
+fn simulate_fishes(init_fishes: &[u64], days: u32) -> u64 {
+    let mut fishes = [0; 9];
+    for &fish in init_fishes {
+        fishes[fish as usize] += 1;
+    }
+    for _ in 0..days {
+        let new_fishes = fishes[0];
+        fishes[0] = 0;
+        fishes.rotate_left(1);
+        fishes[8] += new_fishes;
+        fishes[6] += new_fishes;
+    }
+    fishes.iter().sum()
+}
+
+pub struct Day6;
+
+impl aoc::Solution for Day6 {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_ages(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u64 {
+        simulate_fishes(input, 80)
+    }
+
+    fn part_2(input: &Self::Input) -> u64 {
+        simulate_fishes(input, 256)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3,4,3,1,2
+";
+
+    #[test]
+    fn example() {
+        let input = Day6::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day6::part_1(&input), 5934);
+        assert_eq!(Day6::part_2(&input), 26984457539);
+    }
+}
@@ -0,0 +1,57 @@
+use std::io::BufRead;
+use aoc::ParseError;
+
+fn parse_crabs(input: impl BufRead) -> Result<Vec<i32>, ParseError> {
+    let line = aoc::lines(input).expect("the crab positions")?;
+    line.text.split(',').map(|n| line.parse(n)).collect()
+}
+
+fn min_fuel_required(crabs: &[i32], mut fuel_required: impl FnMut(i32, i32) -> i32) -> i32 {
+    let min = *crabs.iter().min().unwrap();
+    let max = *crabs.iter().max().unwrap();
+    (min..=max)
+        .map(|p| crabs.iter().map(|&c| fuel_required(c, p)).sum())
+        .min()
+        .unwrap()
+}
+
+fn triangular_number(n: i32) -> i32 {
+    n * (n + 1) / 2
+}
+
+pub struct Day7;
+
+impl aoc::Solution for Day7 {
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_crabs(input)
+    }
+
+    fn part_1(input: &Self::Input) -> i32 {
+        min_fuel_required(input, |c, p| (c - p).abs())
+    }
+
+    fn part_2(input: &Self::Input) -> i32 {
+        min_fuel_required(input, |c, p| triangular_number((c - p).abs()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+16,1,2,0,4,2,7,1,2,14
+";
+
+    #[test]
+    fn example() {
+        let input = Day7::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day7::part_1(&input), 37);
+        assert_eq!(Day7::part_2(&input), 168);
+    }
+}
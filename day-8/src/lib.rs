@@ -0,0 +1,166 @@
+use std::io::BufRead;
+use std::collections::{HashSet, HashMap};
+use aoc::ParseError;
+
+type Segments = HashSet<char>;
+
+fn parse_segment_sequence(line: &aoc::Line, segments: &str, len: usize) -> Result<Vec<Segments>, ParseError> {
+    let sequence = segments
+        .split_ascii_whitespace()
+        .map(|s| {
+            if let Some(i) = s.find(|c| !matches!(c, 'a'..='g')) {
+                return Err(line.error_at(&s[i..], "expected a segment between `a` and `g`"));
+            }
+            Ok(s.chars().collect())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if sequence.len() != len {
+        return Err(line.error_at(segments, format!("expected {} digits", len)));
+    }
+    Ok(sequence)
+}
+
+type Record = (Vec<Segments>, Vec<Segments>);
+
+fn parse_records(input: impl BufRead) -> Result<Vec<Record>, ParseError> {
+    aoc::lines(input)
+        .map(|line| {
+            let line = line?;
+            let (attempted, output) = line.split_once(&line.text, " | ")?;
+            Ok((
+                parse_segment_sequence(&line, attempted, 10)?,
+                parse_segment_sequence(&line, output, 4)?
+            ))
+        })
+        .collect()
+}
+
+fn into_key(s: &Segments) -> Vec<char> {
+    let mut s: Vec<char> = s.iter().copied().collect();
+    s.sort_unstable();
+    s
+}
+
+fn find_and_remove(segments: &mut Vec<Segments>, f: impl FnMut(&Segments) -> bool) -> Segments {
+    let position = segments.iter()
+        .position(f)
+        .unwrap();
+    segments.swap_remove(position)
+}
+
+pub struct Day8;
+
+impl aoc::Solution for Day8 {
+    type Input = Vec<Record>;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_records(input)
+    }
+
+    fn part_1(input: &Self::Input) -> usize {
+        let mut ones_fours_sevens_eights = 0;
+        for (attempted, output) in input {
+            let mut decoded = HashMap::new();
+            for segments in attempted {
+                decoded.insert(into_key(segments), match segments.len() {
+                    2 => 1,
+                    4 => 4,
+                    3 => 7,
+                    7 => 8,
+                    _ => continue
+                });
+            }
+            ones_fours_sevens_eights += output
+                .iter()
+                .filter_map(|d| decoded.get(&into_key(d)))
+                .count();
+        }
+        ones_fours_sevens_eights
+    }
+
+    fn part_2(input: &Self::Input) -> i32 {
+        let mut total = 0;
+        for (attempted, output) in input {
+            let mut attempted = attempted.clone();
+            let one = find_and_remove(
+                &mut attempted,
+                |s| s.len() == 2
+            );
+            let four = find_and_remove(
+                &mut attempted,
+                |s| s.len() == 4
+            );
+            let seven = find_and_remove(
+                &mut attempted,
+                |s| s.len() == 3
+            );
+            let eight = find_and_remove(
+                &mut attempted,
+                |s| s.len() == 7
+            );
+            let nine = find_and_remove(
+                &mut attempted,
+                |s| s.len() == 6 && s.is_superset(&four)
+            );
+            let zero = find_and_remove(
+                &mut attempted,
+                |s| s.len() == 6 && s.is_superset(&one)
+            );
+            let six = find_and_remove(
+                &mut attempted,
+                |s| s.len() == 6
+            );
+            let five = find_and_remove(
+                &mut attempted,
+                |s| s.is_subset(&six)
+            );
+            let three = find_and_remove(
+                &mut attempted,
+                |s| s.is_superset(&one)
+            );
+            let two = attempted.pop().unwrap();
+            let decoded = [
+                    zero, one, two, three, four,
+                    five, six, seven, eight, nine
+                ].into_iter()
+                .enumerate()
+                .map(|(i, s)| (into_key(&s), i as i32))
+                .collect::<HashMap<_, _>>();
+            total += output
+                .iter()
+                .rev()
+                .enumerate()
+                .map(|(i, d)| decoded.get(&into_key(d)).unwrap() * 10i32.pow(i as u32))
+                .sum::<i32>();
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
+";
+
+    #[test]
+    fn example() {
+        let input = Day8::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day8::part_1(&input), 26);
+        assert_eq!(Day8::part_2(&input), 61229);
+    }
+}
@@ -0,0 +1,70 @@
+use std::io::BufRead;
+use std::collections::HashSet;
+use aoc::ParseError;
+use aoc::grid::{Grid, Pos};
+
+fn low_points(grid: &Grid<u32>) -> impl Iterator<Item=(Pos, u32)> + '_ {
+    grid.iter()
+        .map(|(p, &h)| (p, h))
+        .filter(|&(pos, height)| grid.neighbours_4(pos).all(|p| grid[p] > height))
+}
+
+fn flood_fill(grid: &Grid<u32>, pos: Pos) -> HashSet<Pos> {
+    fn visit(grid: &Grid<u32>, visited: &mut HashSet<Pos>, pos: Pos) {
+        if visited.contains(&pos) || grid[pos] == 9 {
+            return;
+        }
+        visited.insert(pos);
+        for neighbour in grid.neighbours_4(pos) {
+            visit(grid, visited, neighbour);
+        }
+    }
+    let mut visited = HashSet::new();
+    visit(grid, &mut visited, pos);
+    visited
+}
+
+pub struct Day9;
+
+impl aoc::Solution for Day9 {
+    type Input = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        Grid::parse_digits(input)
+    }
+
+    fn part_1(input: &Self::Input) -> u32 {
+        low_points(input).map(|(_, h)| h + 1).sum()
+    }
+
+    fn part_2(input: &Self::Input) -> usize {
+        let mut basins = low_points(input)
+            .map(|(c, _)| flood_fill(input, c))
+            .collect::<Vec<_>>();
+        basins.sort_unstable_by_key(|b| b.len());
+        basins.iter().rev().take(3).map(|b| b.len()).product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Solution;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2199943210
+3987894921
+9856789892
+8767896789
+9899965678
+";
+
+    #[test]
+    fn example() {
+        let input = Day9::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(Day9::part_1(&input), 15);
+        assert_eq!(Day9::part_2(&input), 1134);
+    }
+}